@@ -1,6 +1,6 @@
 use criterion::measurement::WallTime;
 use std::time::Duration;
-use aoc::aoc13::AOC13;
+use aoc::y2021::aoc13::AOC13;
 use aoc::runner::Runner;
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -12,13 +12,13 @@ fn bench(c: &mut Criterion) {
     c.bench_function("aoc13-parse", |a| {
         a.iter(|| {
             let mut solver = AOC13::default();
-            solver.parse(&data);
+            solver.parse(&data).unwrap();
         });
     });
 
     c.bench_function("aoc13-1", |a| {
         let mut solver = AOC13::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.run_p1();
         });
@@ -26,7 +26,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc13-2", |a| {
         let mut solver = AOC13::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.run_p2();
         });
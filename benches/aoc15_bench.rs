@@ -1,4 +1,4 @@
-use aoc::aoc15::{find_path, generate_risks, Point, AOC15, generate_flowfield};
+use aoc::y2021::aoc15::{find_path, generate_risks, Point, AOC15, generate_flowfield};
 use aoc::runner::Runner;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, Criterion};
@@ -12,25 +12,25 @@ fn bench(c: &mut Criterion) {
     c.bench_function("aoc15-parse", |a| {
         a.iter(|| {
             let mut solver = AOC15::default();
-            solver.parse(&data);
+            solver.parse(&data).unwrap();
         });
     });
 
     c.bench_function("aoc15-1", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| solver.run_p1());
     });
 
     c.bench_function("aoc15-2", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| solver.run_p2());
     });
 
     c.bench_function("aoc15-2-grow-map", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.map.clone().grow();
         });
@@ -38,7 +38,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc15-2-gen-flowfield", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         let mut map = solver.map.clone();
         map.grow();
         let end = Point(map.w as isize - 1, map.h as isize - 1);
@@ -48,7 +48,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc15-2-gen-dirs", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         let mut map = solver.map.clone();
         map.grow();
         let end = Point(map.w as isize - 1, map.h as isize - 1);
@@ -59,7 +59,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc15-2-find-path", |a| {
         let mut solver = AOC15::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         let mut map = solver.map.clone();
         map.grow();
         let end = Point(map.w as isize - 1, map.h as isize - 1);
@@ -1,5 +1,5 @@
-use aoc::aoc20::AOC20;
 use aoc::runner::Runner;
+use aoc::y2021::aoc20::AOC20;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::time::Duration;
@@ -12,19 +12,19 @@ fn bench(c: &mut Criterion) {
     c.bench_function("aoc20-parse", |a| {
         a.iter(|| {
             let mut solver = AOC20::default();
-            solver.parse(&data);
+            solver.parse(&data).unwrap();
         });
     });
 
     c.bench_function("aoc20-1", |a| {
         let mut solver = AOC20::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| solver.run_p1());
     });
 
     c.bench_function("aoc20-2", |a| {
         let mut solver = AOC20::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| solver.run_p2());
     });
 }
@@ -1,5 +1,5 @@
-use aoc::aoc4::AOC4;
 use aoc::runner::Runner;
+use aoc::y2021::aoc4::AOC4;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 mod get_input;
@@ -10,13 +10,13 @@ fn bench(c: &mut Criterion) {
     c.bench_function("aoc4-parse", |a| {
         a.iter(|| {
             let mut solver = AOC4::default();
-            solver.parse(&data);
+            solver.parse(&data).unwrap();
         });
     });
 
     c.bench_function("aoc4-1", |a| {
         let mut solver = AOC4::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.run_p1();
         });
@@ -24,7 +24,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc4-2", |a| {
         let mut solver = AOC4::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.run_p2();
         });
@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use aoc::aoc6::AOC6;
+use aoc::y2021::aoc6::AOC6;
 use aoc::runner::Runner;
 
 mod get_input;
@@ -10,13 +10,13 @@ fn bench(c: &mut Criterion) {
     c.bench_function("aoc6-parse", |a| {
         a.iter(|| {
             let mut solver = AOC6::default();
-            solver.parse(&data);
+            solver.parse(&data).unwrap();
         });
     });
 
     c.bench_function("aoc6-1", |a| {
         let mut solver = AOC6::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(||{
             solver.run_p1();
         });
@@ -24,7 +24,7 @@ fn bench(c: &mut Criterion) {
 
     c.bench_function("aoc6-2", |a| {
         let mut solver = AOC6::default();
-        solver.parse(&data);
+        solver.parse(&data).unwrap();
         a.iter(|| {
             solver.run_p2();
         });
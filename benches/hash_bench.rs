@@ -0,0 +1,151 @@
+//! The fast hasher against SipHash, on maps keyed like the days' and on the
+//! days themselves. Days 12, 19 and 21 only changed their hasher, so their
+//! numbers before the change come from a build with the `std-hash` feature:
+//!
+//! ```text
+//! cargo bench --bench hash_bench --features std-hash -- --save-baseline siphash
+//! cargo bench --bench hash_bench -- --baseline siphash
+//! ```
+//!
+//! Day 14 traded its pair map for a dense table, the map is kept here to
+//! compare with.
+
+use aoc::hash::FxHasher;
+use aoc::runner::Runner;
+use aoc::y2021::aoc12::AOC12;
+use aoc::y2021::aoc14::AOC14;
+use aoc::y2021::aoc19::AOC19;
+use aoc::y2021::aoc21::AOC21;
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, BuildHasherDefault};
+use std::time::Duration;
+
+mod get_input;
+
+type Fx = BuildHasherDefault<FxHasher>;
+
+/// Counts the pairs of a polymer the way day 14 used to
+fn count_pairs<S: BuildHasher + Default>(polymer: &[u8]) -> HashMap<(u8, u8), usize, S> {
+    let mut pairs = HashMap::default();
+    for w in polymer.windows(2) {
+        *pairs.entry((w[0], w[1])).or_insert(0) += 1;
+    }
+    pairs
+}
+
+/// Fills and reads back a memo keyed like the one of day 21
+fn memo<S: BuildHasher + Default>(keys: u16) -> usize {
+    let mut memo: HashMap<[u16; 2], [usize; 2], S> = HashMap::default();
+    for turn in 0..2 {
+        for key in 0..keys {
+            memo.insert([turn, key], [key as usize, turn as usize]);
+        }
+    }
+    (0..keys).map(|key| memo[&[1, key]][0]).sum()
+}
+
+/// Day 14 before the dense pair table, a map of pair counts rebuilt each step
+fn polymer_with_map(input: &[String], steps: usize) -> usize {
+    let template = input[0].as_bytes();
+    let rules: HashMap<(u8, u8), u8> = input[2..]
+        .iter()
+        .map(|l| {
+            let l = l.as_bytes();
+            ((l[0], l[1]), l[6])
+        })
+        .collect();
+
+    let mut pairs: HashMap<(u8, u8), usize> = count_pairs(template);
+    for _ in 0..steps {
+        let mut next = HashMap::new();
+        for (&(a, b), &n) in &pairs {
+            if let Some(&c) = rules.get(&(a, b)) {
+                *next.entry((a, c)).or_insert(0) += n;
+                *next.entry((c, b)).or_insert(0) += n;
+            }
+        }
+        pairs = next;
+    }
+
+    let mut counts = HashMap::new();
+    for ((a, _), n) in pairs {
+        *counts.entry(a).or_insert(0) += n;
+    }
+    *counts.get_mut(template.last().unwrap()).unwrap() += 1;
+    counts.values().max().unwrap() - counts.values().min().unwrap()
+}
+
+fn bench_maps(c: &mut Criterion) {
+    let polymer: Vec<u8> = (0..100_000).map(|i| b'A' + (i * 7 % 10) as u8).collect();
+
+    let mut group = c.benchmark_group("hash-pairs");
+    group.bench_function("siphash", |a| {
+        a.iter(|| count_pairs::<RandomState>(black_box(&polymer)));
+    });
+    group.bench_function("fast", |a| {
+        a.iter(|| count_pairs::<Fx>(black_box(&polymer)));
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("hash-memo");
+    group.bench_function("siphash", |a| {
+        a.iter(|| memo::<RandomState>(black_box(44_100)));
+    });
+    group.bench_function("fast", |a| {
+        a.iter(|| memo::<Fx>(black_box(44_100)));
+    });
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    let data = get_input::get_input(14);
+    let mut solver = AOC14::default();
+    solver.parse(&data).unwrap();
+    assert_eq!(solver.run_p2(), polymer_with_map(&data, 40));
+
+    let mut group = c.benchmark_group("hash-aoc14-2");
+    group.bench_function("map", |a| {
+        a.iter(|| polymer_with_map(black_box(&data), 40));
+    });
+    group.bench_function("dense", |a| {
+        a.iter(|| solver.run_p2());
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("hash-days");
+    let data = get_input::get_input(12);
+    let mut solver = AOC12::default();
+    solver.parse(&data).unwrap();
+    group.bench_function("aoc12-2", |a| {
+        a.iter(|| solver.run_p2());
+    });
+
+    let data = get_input::get_input(19);
+    let mut solver = AOC19::default();
+    solver.parse(&data).unwrap();
+    group.bench_function("aoc19-1", |a| {
+        a.iter(|| solver.run_p1());
+    });
+
+    let data = get_input::get_input(21);
+    let mut solver = AOC21::default();
+    solver.parse(&data).unwrap();
+    group.bench_function("aoc21-2", |a| {
+        a.iter(|| solver.run_p2());
+    });
+    group.finish();
+}
+
+fn setup() -> Criterion<WallTime> {
+    Criterion::default().measurement_time(Duration::from_secs_f32(5.0))
+}
+
+criterion_group! {
+    name = benches;
+    config = setup();
+    targets = bench_maps, bench_days
+}
+criterion_main!(benches);
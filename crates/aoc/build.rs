@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap();
+
+    // only the ffi module is exported, parsing the whole crate trips over its generic aliases.
+    // The header goes to OUT_DIR, a test checks the copy in include/ still matches it
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    match cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{}/src/ffi.rs", crate_dir))
+        .generate()
+    {
+        Ok(bindings) => {
+            bindings.write_to_file(Path::new(&out_dir).join("aoc.h"));
+        }
+        Err(e) => println!("cargo:warning=Couldnt generate aoc.h: {}", e),
+    }
+
+    if std::env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        embed_inputs(Path::new(&crate_dir));
+    }
+}
+
+/// Every file below `dir`, sorted
+fn files(dir: &Path, found: &mut Vec<PathBuf>) {
+    // new files only change the directory they are added to
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Couldnt read {}: {}", dir.display(), e))
+        .map(|e| e.unwrap().path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            files(&path, found);
+        } else {
+            found.push(path);
+        }
+    }
+}
+
+/// Writes `embedded.rs` with every file of the resources directory, the one
+/// in `AOC_RESOURCES` or else the one at the root of the repository
+fn embed_inputs(crate_dir: &Path) {
+    println!("cargo:rerun-if-env-changed=AOC_RESOURCES");
+    let resources = match std::env::var_os("AOC_RESOURCES") {
+        Some(dir) => PathBuf::from(dir),
+        None => crate_dir.join("../../resources"),
+    };
+    let resources = resources
+        .canonicalize()
+        .unwrap_or_else(|e| panic!("Couldnt find resources at {}: {}", resources.display(), e));
+
+    let mut found = Vec::new();
+    files(&resources, &mut found);
+
+    let mut out = String::from("pub static FILES: &[(&str, &[u8])] = &[\n");
+    for path in found {
+        let name = path.strip_prefix(&resources).unwrap().to_str().unwrap();
+        out.push_str(&format!(
+            "    ({:?}, include_bytes!({:?})),\n",
+            name.replace('\\', "/"),
+            path
+        ));
+    }
+    out.push_str("];\n");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("embedded.rs"), out).unwrap();
+}
@@ -0,0 +1,37 @@
+//! Example solver plugin, an alternative day 1 that compares the depths the
+//! sliding windows don't share instead of summing them.
+//!
+//! Build it with `cargo build --example plugin` and copy the library into the
+//! plugins directory, then run it with `--solver windows`.
+
+use aoc::diagnostic::{lines, ParseError};
+use aoc::plugin::Registrar;
+use aoc::runner::Runner;
+
+#[derive(Default)]
+struct Windows {
+    depths: Vec<u32>,
+}
+
+impl Runner for Windows {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        self.depths = lines(input)
+            .map(|l| l.number::<u32>(l.text))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        self.depths.windows(2).filter(|e| e[1] > e[0]).count()
+    }
+
+    fn run_p2(&self) -> usize {
+        self.depths.windows(4).filter(|e| e[3] > e[0]).count()
+    }
+}
+
+fn register(registrar: &mut Registrar) {
+    registrar.register(2021, 1, "windows", || Box::new(Windows::default()));
+}
+
+aoc::export_plugin!(register);
@@ -0,0 +1,38 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Solves `part` of `day` in `year` for the `len` bytes of UTF-8 text at
+// `input`.
+//
+// Returns the answer as a string that must be released with
+// `aoc_free_string`, or null on failure in which case `aoc_last_error`
+// describes what went wrong.
+//
+// # Safety
+//
+// `input` must point to at least `len` readable bytes, it may be null when
+// `len` is 0.
+char *aoc_solve(uint32_t year, uint32_t day, uint32_t part, const uint8_t *input, size_t len);
+
+// Releases a string returned by `aoc_solve`, null is ignored.
+//
+// # Safety
+//
+// `s` must come from `aoc_solve` and not have been freed already.
+void aoc_free_string(char *s);
+
+// Message of the last failed `aoc_solve` on the calling thread, or null.
+//
+// The string is owned by the library and stays valid until the next call to
+// `aoc_solve` on the same thread.
+const char *aoc_last_error(void);
+
+#endif /* AOC_H */
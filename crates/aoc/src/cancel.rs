@@ -0,0 +1,122 @@
+//! Cooperative cancellation for solvers that can run for a long time.
+//!
+//! A run gets a time budget with [`with_budget`], long running loops call
+//! [`checkpoint`] which unwinds out of the solver once the budget is spent.
+//! Solvers that would rather stop on their own can poll [`is_cancelled`].
+
+use std::cell::Cell;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = Cell::new(None);
+    /// Set while panics on this thread are expected and not worth printing
+    static QUIET: Cell<bool> = Cell::new(false);
+}
+
+/// Budget of runs that don't choose one, like [`crate::solve`] and the
+/// differential checks
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(60);
+
+/// Panic payload used to unwind out of a cancelled solver
+struct Cancelled;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// True once the budget of the run on this thread is spent
+pub fn is_cancelled() -> bool {
+    DEADLINE.with(|d| d.get().map_or(false, |d| Instant::now() >= d))
+}
+
+/// Unwinds out of the current run if its budget is spent, does nothing
+/// outside of `with_budget`.
+pub fn checkpoint() {
+    if is_cancelled() {
+        panic::panic_any(Cancelled);
+    }
+}
+
+/// Keeps the panic hook quiet about cancellations and panics inside
+/// [`quietly`], other panics still reach the hook that was installed before.
+fn install_hook() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let cancelled = info.payload().downcast_ref::<Cancelled>().is_some();
+            if !cancelled && !QUIET.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f` without printing the panics it raises on this thread, for callers
+/// that expect solvers to panic on some inputs. Panics still unwind as usual.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    install_hook();
+
+    let outer = QUIET.with(|q| q.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|q| q.set(outer));
+
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Runs `f` with a time budget. Returns `TimedOut` if `f` hit a checkpoint
+/// after the budget was spent, or returned late. Other panics keep unwinding.
+pub fn with_budget<T>(budget: Duration, f: impl FnOnce() -> T) -> Result<T, TimedOut> {
+    install_hook();
+
+    let deadline = Instant::now() + budget;
+    let outer = DEADLINE.with(|d| d.replace(Some(d.get().map_or(deadline, |o| o.min(deadline)))));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let late = is_cancelled();
+    DEADLINE.with(|d| d.set(outer));
+
+    match result {
+        Ok(_) if late => Err(TimedOut(budget)),
+        Ok(value) => Ok(value),
+        Err(payload) if payload.is::<Cancelled>() => Err(TimedOut(budget)),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+mod tests {
+    #[test]
+    fn budget_stops_at_checkpoint() {
+        use super::*;
+
+        let spin = || loop {
+            checkpoint();
+        };
+        assert_eq!(
+            Err(TimedOut(Duration::from_millis(10))),
+            with_budget(Duration::from_millis(10), spin)
+        );
+
+        assert_eq!(Ok(1), with_budget(Duration::from_secs(10), || 1));
+        assert!(!is_cancelled());
+        checkpoint();
+    }
+
+    #[test]
+    fn quiet_panics_still_unwind() {
+        use super::*;
+
+        assert!(panic::catch_unwind(|| quietly(|| panic!("not printed"))).is_err());
+        assert!(!QUIET.with(Cell::get));
+        assert_eq!(2, quietly(|| 2));
+    }
+}
@@ -0,0 +1,112 @@
+//! Puzzle inputs are kept encrypted in the repository, as the puzzle author
+//! asks not to publish them. The plain text inputs are ignored by git, one
+//! that isn't encrypted yet is read as is until `encrypt-inputs` converts it.
+//!
+//! An encrypted input is `dayN.txt.enc`, a random nonce followed by the
+//! ChaCha20-Poly1305 ciphertext. The key is 32 bytes written as hex, read from
+//! `AOC_INPUT_KEY` or else from the `.aoc_key` file.
+
+use std::path::Path;
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+pub const KEY_FILE: &str = ".aoc_key";
+
+/// Extension added to the name of an encrypted input
+pub const EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 12;
+
+pub fn parse_key(hex: &str, source: &str) -> Result<[u8; 32], String> {
+    let hex = hex.trim();
+    let bad_key = || format!("Key in {} should be 64 hex characters", source);
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(bad_key());
+    }
+
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| bad_key())?;
+    }
+    Ok(key)
+}
+
+/// The key from `AOC_INPUT_KEY` or the key file, `None` when neither is set.
+pub fn find_key() -> Result<Option<[u8; 32]>, String> {
+    if let Ok(hex) = std::env::var(KEY_VAR) {
+        return parse_key(&hex, KEY_VAR).map(Some);
+    }
+    match std::fs::read_to_string(KEY_FILE) {
+        Ok(hex) => parse_key(&hex, KEY_FILE).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+/// A new random key as hex, ready to be written to the key file
+pub fn generate_key() -> String {
+    rand::random::<[u8; 32]>()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub fn encrypt(key: &[u8; 32], plain: &[u8]) -> Vec<u8> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce = rand::random::<[u8; NONCE_LEN]>();
+
+    let mut out = nonce.to_vec();
+    out.extend(cipher.encrypt(Nonce::from_slice(&nonce), plain).unwrap());
+    out
+}
+
+pub fn decrypt(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < NONCE_LEN {
+        return Err(String::from("Encrypted input is too short"));
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    ChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| String::from("Couldnt decrypt input, the key is wrong or the file is damaged"))
+}
+
+/// Reads and decrypts `path`, with an error telling how to set the key when
+/// there is none.
+pub fn read_encrypted(path: &Path) -> Result<String, String> {
+    let key = find_key()?.ok_or_else(|| {
+        format!(
+            "{} is encrypted, set {} or put the key in {}",
+            path.display(),
+            KEY_VAR,
+            KEY_FILE
+        )
+    })?;
+    read_encrypted_with(&key, path)
+}
+
+/// Reads and decrypts `path` with `key` instead of looking for one
+pub fn read_encrypted_with(key: &[u8; 32], path: &Path) -> Result<String, String> {
+    let data = crate::input::read_bytes(path)?;
+    let plain = decrypt(key, &data).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    String::from_utf8(plain).map_err(|_| format!("{} is not valid UTF-8", path.display()))
+}
+
+mod tests {
+    #[test]
+    fn encrypt_roundtrip() {
+        use super::*;
+
+        let key = parse_key(&generate_key(), KEY_VAR).unwrap();
+        let data = encrypt(&key, b"3,4,3,1,2");
+        assert_ne!(&data[NONCE_LEN..], b"3,4,3,1,2");
+        assert_eq!(Ok(b"3,4,3,1,2".to_vec()), decrypt(&key, &data));
+
+        let other = parse_key(&generate_key(), KEY_VAR).unwrap();
+        assert!(decrypt(&other, &data).is_err());
+        assert!(parse_key("abc", KEY_VAR).is_err());
+    }
+}
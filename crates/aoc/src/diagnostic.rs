@@ -0,0 +1,254 @@
+//! Compiler style errors for input a day couldnt parse.
+//!
+//! Parsers walk their input as [`Line`]s and use its helpers, which point the
+//! error at the part of the line that was wrong. `runner::get_runner` fills in
+//! the day and its expected input format before the error reaches the user.
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use colored::Colorize;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// Boxed so the `Result`s of every parser stay small
+    pub context: Box<Context>,
+}
+
+/// Where a [`ParseError`] points and what would have helped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    /// zero based index of the input line
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    /// the offending input line
+    pub text: String,
+    pub hint: Option<String>,
+    pub day: Option<(usize, usize)>,
+    pub expected: Option<&'static str>,
+}
+
+impl ParseError {
+    pub fn with_hint(mut self, hint: impl Into<String>) -> ParseError {
+        self.context.hint = Some(hint.into());
+        self
+    }
+
+    /// Renders the error with the line, a caret under the column, the expected
+    /// format and the hint.
+    pub fn render(&self) -> String {
+        let context = &self.context;
+        let number = (context.line + 1).to_string();
+        let gutter = " ".repeat(number.len());
+        let bar = "|".blue().bold();
+
+        let mut out = format!("{}: {}\n", "error".red().bold(), self.message.bold());
+        let location = match context.day {
+            Some((year, day)) => format!("{} day {}, line {}", year, day, number),
+            None => format!("line {}", number),
+        };
+        out += &format!(
+            "{}{} {}:{}\n",
+            gutter,
+            "-->".blue().bold(),
+            location,
+            context.column + 1
+        );
+        out += &format!("{} {}\n", gutter, bar);
+        out += &format!("{} {} {}\n", number.blue().bold(), bar, context.text);
+        out += &format!(
+            "{} {} {}{}\n",
+            gutter,
+            bar,
+            " ".repeat(context.column),
+            "^".repeat(context.width.max(1)).red().bold()
+        );
+        if let Some(expected) = context.expected {
+            out += &format!("{} {} expected: {}\n", gutter, "=".blue().bold(), expected);
+        }
+        if let Some(hint) = &context.hint {
+            out += &format!("{} {} hint: {}\n", gutter, "=".blue().bold(), hint);
+        }
+        out
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}:{}: {}",
+            self.context.line + 1,
+            self.context.column + 1,
+            self.message
+        )
+    }
+}
+
+/// Shows the full diagnostic, so an `unwrap` in a test points at the input
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\n{}", self.render())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of input and its index, for pointing errors at it
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column of `part` in the line, `part` is expected to be a slice of it
+    fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        offset.min(self.text.len())
+    }
+
+    pub fn error_at(&self, column: usize, width: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            context: Box::new(Context {
+                line: self.index,
+                column,
+                width,
+                text: self.text.to_string(),
+                hint: None,
+                day: None,
+                expected: None,
+            }),
+        }
+    }
+
+    /// Error pointing at `part`, a slice of the line
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        self.error_at(self.column(part), part.len(), message)
+    }
+
+    pub fn number<T: FromStr>(&self, part: &'a str) -> Result<T, ParseError> {
+        part.parse::<T>().map_err(|_| {
+            if part.is_empty() {
+                self.error(part, "expected a number, found nothing")
+            } else {
+                self.error(part, format!("expected a number, found `{}`", part))
+            }
+        })
+    }
+
+    pub fn split_once(&self, part: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(sep)
+            .ok_or_else(|| self.error(part, format!("expected `{}`", sep)))
+    }
+
+    pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        part.strip_prefix(prefix).ok_or_else(|| {
+            let width = prefix.len().min(part.len());
+            self.error(&part[..width], format!("expected `{}`", prefix))
+        })
+    }
+
+    /// Every character of the line as a single digit
+    pub fn digits(&self) -> Result<Vec<u8>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(d) => Ok(d as u8),
+                None => {
+                    Err(self.error_at(i, c.len_utf8(), format!("expected a digit, found `{}`", c)))
+                }
+            })
+            .collect()
+    }
+}
+
+/// The line at `index`, or an error pointing past the end of the input
+pub fn line(input: &[String], index: usize) -> Result<Line<'_>, ParseError> {
+    match input.get(index) {
+        Some(text) => Ok(Line { index, text }),
+        None => Err(Line {
+            index: input.len(),
+            text: "",
+        }
+        .error_at(0, 1, "unexpected end of input")),
+    }
+}
+
+pub fn lines(input: &[String]) -> impl Iterator<Item = Line<'_>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Rows of single digits, all as wide as the first one
+pub fn digit_grid(input: &[String]) -> Result<Vec<Vec<u8>>, ParseError> {
+    let width = line(input, 0)?.text.len();
+    lines(input)
+        .map(|l| {
+            let row = l.digits()?;
+            if row.len() != width {
+                let column = width.min(row.len());
+                return Err(l
+                    .error_at(
+                        column,
+                        1,
+                        format!("expected {} digits, found {}", width, row.len()),
+                    )
+                    .with_hint("every row needs as many digits as the first one"));
+            }
+            Ok(row)
+        })
+        .collect()
+}
+
+mod tests {
+    #[test]
+    fn render_points_at_column() {
+        colored::control::set_override(false);
+
+        let input = vec![String::from("199"), String::from("2x0")];
+        let line = super::line(&input, 1).unwrap();
+        let mut error = line.number::<usize>(&line.text[1..2]).unwrap_err();
+        error.context.day = Some((2021, 1));
+        error.context.expected = Some("one depth per line, like `199`");
+        let error = error.with_hint("depths are whole numbers");
+
+        assert_eq!(
+            "error: expected a number, found `x`
+ --> 2021 day 1, line 2:2
+  |
+2 | 2x0
+  |  ^
+  = expected: one depth per line, like `199`
+  = hint: depths are whole numbers
+",
+            error.render()
+        );
+        assert_eq!("line 2:2: expected a number, found `x`", error.to_string());
+        assert_eq!(2, super::line(&input, 5).unwrap_err().context.line);
+    }
+
+    #[test]
+    fn days_point_at_bad_input() {
+        use crate::{solve, Error};
+
+        let wide = "1".repeat(33);
+        let cases = [
+            (3, "0101\n\n0110", (1, 0)),
+            (3, wide.as_str(), (0, 0)),
+            (12, "start-A\nA-", (1, 2)),
+            (12, "-end", (0, 0)),
+        ];
+        for (day, input, at) in cases {
+            match solve(day, 1, input) {
+                Err(Error::Parse(e)) => assert_eq!(at, (e.context.line, e.context.column)),
+                other => panic!("day {} on {:?} gave {:?}", day, input, other),
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::cancel::{quietly, with_budget, DEFAULT_BUDGET};
+use crate::generate::{generate, YEAR};
+use crate::runner::{get_runner, Runner};
+
+/// A generated input where a solver and its reference give different answers.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: usize,
+    pub part: usize,
+    pub seed: u64,
+    pub size: usize,
+    pub input: Vec<String>,
+    pub expected: usize,
+    /// `None` when the solver panicked or timed out on the input
+    pub got: Option<usize>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "day {} part {} disagrees with its reference (seed {}, size {})",
+            self.day, self.part, self.seed, self.size
+        )?;
+        match self.got {
+            Some(got) => writeln!(f, "expected {} - got answer {}", self.expected, got)?,
+            None => writeln!(
+                f,
+                "expected {} - solver panicked or timed out",
+                self.expected
+            )?,
+        }
+        writeln!(f, "smallest input ({} lines):", self.input.len())?;
+        for l in &self.input {
+            writeln!(f, "{}", l)?;
+        }
+        Ok(())
+    }
+}
+
+enum Outcome {
+    Agree,
+    Disagree(usize, Option<usize>),
+    /// the reference panicked, so the input is not usable
+    Invalid,
+    NoReference,
+}
+
+/// Makes a fresh runner of the day being checked
+type Source<'a> = &'a dyn Fn() -> Box<dyn Runner>;
+
+fn compare(source: Source, part: usize, input: &Vec<String>) -> Outcome {
+    let run = |reference: bool| {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            with_budget(DEFAULT_BUDGET, || {
+                let mut runner = source();
+                runner.parse(input).map(|_| match (part, reference) {
+                    (1, false) => Some(runner.run_p1()),
+                    (1, true) => runner.reference_p1(),
+                    (_, false) => Some(runner.run_p2()),
+                    (_, true) => runner.reference_p2(),
+                })
+            })
+        }))
+    };
+
+    // a timeout counts as a panic, the input is too much for the reference
+    // or the solver got stuck on it
+    let expected = match run(true) {
+        Ok(Ok(Ok(Some(expected)))) => expected,
+        Ok(Ok(Ok(None))) => return Outcome::NoReference,
+        _ => return Outcome::Invalid,
+    };
+    let got = match run(false) {
+        Ok(Ok(Ok(got))) => got,
+        _ => None,
+    };
+
+    if got == Some(expected) {
+        Outcome::Agree
+    } else {
+        Outcome::Disagree(expected, got)
+    }
+}
+
+/// Drops lines one at a time for as long as the answers still disagree.
+fn shrink(source: Source, part: usize, mut input: Vec<String>) -> Vec<String> {
+    let mut i = input.len();
+    while i > 0 {
+        i -= 1;
+        let mut smaller = input.clone();
+        smaller.remove(i);
+
+        if let Outcome::Disagree(..) = compare(source, part, &smaller) {
+            input = smaller;
+            i = i.min(input.len());
+        }
+    }
+    input
+}
+
+fn find_disagreement(
+    day: usize,
+    part: usize,
+    seeds: u64,
+    max_size: usize,
+    source: Source,
+) -> Result<Option<Disagreement>, String> {
+    for size in 1..=max_size {
+        for seed in 0..seeds {
+            let input = generate(day, seed, Some(size))?;
+
+            match compare(source, part, &input) {
+                Outcome::Agree | Outcome::Invalid => continue,
+                Outcome::NoReference => {
+                    return Err(format!("Day {} part {} has no reference solver", day, part))
+                }
+                Outcome::Disagree(..) => {
+                    let input = shrink(source, part, input);
+                    let (expected, got) = match compare(source, part, &input) {
+                        Outcome::Disagree(expected, got) => (expected, got),
+                        _ => unreachable!(),
+                    };
+
+                    return Ok(Some(Disagreement {
+                        day,
+                        part,
+                        seed,
+                        size,
+                        input,
+                        expected,
+                        got,
+                    }));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Runs a part and its reference on generated inputs of growing size, trying
+/// `seeds` seeds per size, and returns the smallest input they disagree on.
+pub fn check(
+    day: usize,
+    part: usize,
+    seeds: u64,
+    max_size: usize,
+) -> Result<Option<Disagreement>, String> {
+    if get_runner(YEAR, day).is_none() {
+        return Err(format!("Runner for day {} not implemented", day));
+    }
+
+    let source = || get_runner(YEAR, day).unwrap();
+    // solvers are expected to panic on some of the shrunk inputs
+    quietly(|| find_disagreement(day, part, seeds, max_size, &source))
+}
+
+mod tests {
+    #[test]
+    fn references_agree() {
+        let without_reference = |day, part| match (day, part) {
+            // solved the way the puzzle tells it, a reference would repeat the solver
+            (1 | 2 | 3 | 4 | 7 | 8 | 9 | 10 | 11 | 16, _) | (21, 1) => true,
+            // the fish and the polymer double every few steps, there are far
+            // too many to go through one by one
+            (6, 2) | (14, 2) => true,
+            // the answer is the letters `show` draws
+            (13, 2) => true,
+            // the games split into too many universes to play without the memo
+            (21, 2) => true,
+            // not solved yet
+            (18 | 19, _) => true,
+            _ => false,
+        };
+
+        for day in 1..=22 {
+            for part in 1..=2 {
+                match super::check(day, part, 3, 6) {
+                    Ok(Some(disagreement)) => panic!("{}", disagreement),
+                    Ok(None) => assert!(!without_reference(day, part), "{} {}", day, part),
+                    Err(e) => assert!(without_reference(day, part), "{}", e),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn finds_smallest_disagreement() {
+        use super::*;
+        use crate::diagnostic::ParseError;
+
+        /// Day 22 with its answer off by one once there are three cuboids
+        struct Broken {
+            inner: Box<dyn Runner>,
+            lines: usize,
+        }
+
+        impl Runner for Broken {
+            fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+                self.lines = input.len();
+                self.inner.parse(input)
+            }
+
+            fn run_p1(&self) -> usize {
+                self.inner.run_p1() + (self.lines >= 3) as usize
+            }
+
+            fn run_p2(&self) -> usize {
+                self.inner.run_p2()
+            }
+
+            fn reference_p1(&self) -> Option<usize> {
+                self.inner.reference_p1()
+            }
+        }
+
+        let source = || -> Box<dyn Runner> {
+            Box::new(Broken {
+                inner: get_runner(YEAR, 22).unwrap(),
+                lines: 0,
+            })
+        };
+        let disagreement = find_disagreement(22, 1, 3, 6, &source).unwrap().unwrap();
+        assert_eq!(3, disagreement.size);
+        assert_eq!(3, disagreement.input.len());
+        assert_eq!(Some(disagreement.expected + 1), disagreement.got);
+    }
+}
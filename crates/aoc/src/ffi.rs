@@ -0,0 +1,86 @@
+//! C API over [`crate::solve`], the header is checked in at `include/aoc.h`.
+
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+fn set_last_error(msg: String) {
+    let msg = CString::new(msg.replace('\0', "")).unwrap();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+/// Solves `part` of `day` in `year` for the `len` bytes of UTF-8 text at
+/// `input`.
+///
+/// Returns the answer as a string that must be released with
+/// `aoc_free_string`, or null on failure in which case `aoc_last_error`
+/// describes what went wrong.
+///
+/// # Safety
+///
+/// `input` must point to at least `len` readable bytes, it may be null when
+/// `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: *const u8,
+    len: usize,
+) -> *mut c_char {
+    LAST_ERROR.with(|e| *e.borrow_mut() = None);
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else if input.is_null() {
+        set_last_error(String::from("Input is null"));
+        return ptr::null_mut();
+    } else {
+        std::slice::from_raw_parts(input, len)
+    };
+
+    let input = match std::str::from_utf8(bytes) {
+        Ok(input) => input,
+        Err(e) => {
+            set_last_error(format!("Input is not valid UTF-8: {}", e));
+            return ptr::null_mut();
+        }
+    };
+
+    match crate::solve_year(year as usize, day as usize, part as usize, input) {
+        Ok(answer) => CString::new(answer.to_string()).unwrap().into_raw(),
+        Err(e) => {
+            set_last_error(e.to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases a string returned by `aoc_solve`, null is ignored.
+///
+/// # Safety
+///
+/// `s` must come from `aoc_solve` and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Message of the last failed `aoc_solve` on the calling thread, or null.
+///
+/// The string is owned by the library and stays valid until the next call to
+/// `aoc_solve` on the same thread.
+#[no_mangle]
+pub extern "C" fn aoc_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| match &*e.borrow() {
+        Some(msg) => msg.as_ptr(),
+        None => ptr::null(),
+    })
+}
@@ -0,0 +1,94 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::y2021::*;
+
+/// Year the generators write inputs for
+pub const YEAR: usize = 2021;
+
+pub type GenRng = StdRng;
+type Generator = fn(&mut GenRng, usize) -> Vec<String>;
+
+/// What the `size` knob means for a day, and the size used when none is given.
+pub fn size_info(day: usize) -> Option<(&'static str, usize)> {
+    let info = match day {
+        1 => ("number of depth readings", 2000),
+        2 => ("number of commands", 1000),
+        3 => ("number of diagnostic lines", 1000),
+        4 => ("number of bingo boards", 100),
+        5 => ("number of vent lines", 500),
+        6 => ("number of lanternfish", 300),
+        7 => ("number of crabs", 1000),
+        8 => ("number of displays", 200),
+        9 => ("grid size", 100),
+        10 => ("number of lines", 100),
+        11 => ("grid size", 10),
+        12 => ("number of small caves", 6),
+        13 => ("number of dots", 800),
+        14 => ("template length", 20),
+        15 => ("grid size", 100),
+        16 => ("packet depth", 4),
+        17 => ("distance to the target area", 150),
+        18 => ("number of snailfish numbers", 100),
+        19 => ("scanner count", 5),
+        20 => ("image size", 100),
+        21 => ("unused", 0),
+        22 => ("number of cuboids", 420),
+        _ => return None,
+    };
+    Some(info)
+}
+
+fn get_generator(day: usize) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => aoc1::generate,
+        2 => aoc2::generate,
+        3 => aoc3::generate,
+        4 => aoc4::generate,
+        5 => aoc5::generate,
+        6 => aoc6::generate,
+        7 => aoc7::generate,
+        8 => aoc8::generate,
+        9 => aoc9::generate,
+        10 => aoc10::generate,
+        11 => aoc11::generate,
+        12 => aoc12::generate,
+        13 => aoc13::generate,
+        14 => aoc14::generate,
+        15 => aoc15::generate,
+        16 => aoc16::generate,
+        17 => aoc17::generate,
+        18 => aoc18::generate,
+        19 => aoc19::generate,
+        20 => aoc20::generate,
+        21 => aoc21::generate,
+        22 => aoc22::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Generates a random puzzle input for `day`. The same seed and size always
+/// produce the same input.
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Result<Vec<String>, String> {
+    let generator = get_generator(day).ok_or(format!("No generator for day {}", day))?;
+    let (_, default_size) = size_info(day).unwrap();
+
+    let mut rng = GenRng::seed_from_u64(seed);
+    Ok(generator(&mut rng, size.unwrap_or(default_size)))
+}
+
+mod tests {
+    #[test]
+    fn generate_is_reproducible() {
+        for day in 1..=22 {
+            let a = super::generate(day, 2021, Some(8)).unwrap();
+            let b = super::generate(day, 2021, Some(8)).unwrap();
+            assert_eq!(a, b, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn generate_unknown_day() {
+        assert!(super::generate(26, 0, None).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+//! Two dimensional grids that know what lies outside of them.
+//!
+//! [`DenseGrid`] has a fixed size, [`SparseGrid`] only stores the cells that
+//! were written to and [`InfiniteGrid`] grows to fit them. Reading a cell that
+//! isn't stored gives the default value of the grid, which can be changed for
+//! planes like the image of day 20 where the whole background flips.
+
+use crate::hash::FastHashMap;
+use crate::intervals::{Cuboid, Interval};
+
+/// `(x, y)` with y growing downwards
+pub type Pos = (isize, isize);
+
+pub trait Grid<T> {
+    /// The value at `pos`, the default when the cell isn't stored
+    fn get(&self, pos: Pos) -> &T;
+
+    /// The cell at `pos`, stored with the default value if it wasn't yet
+    fn get_mut(&mut self, pos: Pos) -> &mut T;
+
+    /// The smallest box around the stored cells, `None` when there are none
+    fn bounds(&self) -> Option<Cuboid<2>>;
+
+    fn default_value(&self) -> &T;
+
+    /// Changes the value of every cell that isn't stored
+    fn set_default(&mut self, value: T);
+
+    fn set(&mut self, pos: Pos, value: T) {
+        *self.get_mut(pos) = value;
+    }
+
+    /// Number of cells inside the bounds that `pred` holds for
+    fn count(&self, pred: impl Fn(&T) -> bool) -> usize
+    where
+        Self: Sized,
+    {
+        self.bounds().map_or(0, |bounds| {
+            positions(bounds).filter(|&e| pred(self.get(e))).count()
+        })
+    }
+
+    /// A line of pixels per row of `area`
+    fn draw(&self, area: Cuboid<2>, pixel: impl Fn(&T) -> char) -> String
+    where
+        Self: Sized,
+    {
+        let [xs, ys] = area.0;
+        let rows = (ys.start..=ys.end).map(|y| {
+            (xs.start..=xs.end)
+                .map(|x| pixel(self.get((x, y))))
+                .collect::<String>()
+        });
+        rows.collect::<Vec<String>>().join("\n")
+    }
+}
+
+/// Every position in `area`, a row at a time
+pub fn positions(area: Cuboid<2>) -> impl Iterator<Item = Pos> {
+    let [xs, ys] = area.0;
+    (ys.start..=ys.end).flat_map(move |y| (xs.start..=xs.end).map(move |x| (x, y)))
+}
+
+/// `bounds` grown to hold `pos`
+fn span(bounds: Option<Cuboid<2>>, pos: Pos) -> Cuboid<2> {
+    let [xs, ys] = bounds.map_or(
+        [Interval::new(pos.0, pos.0), Interval::new(pos.1, pos.1)],
+        |e| e.0,
+    );
+    Cuboid([
+        Interval::new(xs.start.min(pos.0), xs.end.max(pos.0)),
+        Interval::new(ys.start.min(pos.1), ys.end.max(pos.1)),
+    ])
+}
+
+/// A `width` by `height` grid with its corner at `(0, 0)`
+#[derive(Debug, Clone)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    default: T,
+}
+
+impl<T: Clone> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, default: T) -> DenseGrid<T> {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![default.clone(); width * height],
+            default,
+        }
+    }
+
+    /// Panics unless all rows have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>, default: T) -> DenseGrid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|e| e.len() == width),
+            "rows differ in length"
+        );
+
+        DenseGrid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            default,
+        }
+    }
+}
+
+impl<T> DenseGrid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): Pos) -> Option<usize> {
+        let inside =
+            (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y);
+        inside.then(|| y as usize * self.width + x as usize)
+    }
+}
+
+impl<T> Grid<T> for DenseGrid<T> {
+    fn get(&self, pos: Pos) -> &T {
+        self.index(pos).map_or(&self.default, |i| &self.cells[i])
+    }
+
+    /// Panics outside the grid, it doesn't grow
+    fn get_mut(&mut self, pos: Pos) -> &mut T {
+        match self.index(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+
+    fn bounds(&self) -> Option<Cuboid<2>> {
+        (self.width > 0 && self.height > 0).then(|| {
+            Cuboid([
+                Interval::new(0, self.width as isize - 1),
+                Interval::new(0, self.height as isize - 1),
+            ])
+        })
+    }
+
+    fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    fn set_default(&mut self, value: T) {
+        self.default = value;
+    }
+}
+
+/// Only stores the cells that were written to
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: FastHashMap<Pos, T>,
+    bounds: Option<Cuboid<2>>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: FastHashMap::default(),
+            bounds: None,
+            default,
+        }
+    }
+
+    /// The stored cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+}
+
+impl<T: Clone> Grid<T> for SparseGrid<T> {
+    fn get(&self, pos: Pos) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    fn get_mut(&mut self, pos: Pos) -> &mut T {
+        self.bounds = Some(span(self.bounds, pos));
+        let default = &self.default;
+        self.cells.entry(pos).or_insert_with(|| default.clone())
+    }
+
+    fn bounds(&self) -> Option<Cuboid<2>> {
+        self.bounds
+    }
+
+    fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    fn set_default(&mut self, value: T) {
+        self.default = value;
+    }
+
+    /// Only looks at the stored cells
+    fn count(&self, pred: impl Fn(&T) -> bool) -> usize {
+        let stored = self.cells.values().filter(|e| pred(e)).count();
+        match self.bounds {
+            Some(bounds) if pred(&self.default) => stored + bounds.volume() - self.cells.len(),
+            _ => stored,
+        }
+    }
+}
+
+/// A dense grid that grows in any direction to fit the cells written to
+#[derive(Debug, Clone)]
+pub struct InfiniteGrid<T> {
+    /// The allocated cells, at least the bounds
+    area: Option<Cuboid<2>>,
+    bounds: Option<Cuboid<2>>,
+    cells: Vec<T>,
+    default: T,
+}
+
+impl<T: Clone> InfiniteGrid<T> {
+    pub fn new(default: T) -> InfiniteGrid<T> {
+        InfiniteGrid {
+            area: None,
+            bounds: None,
+            cells: Vec::new(),
+            default,
+        }
+    }
+
+    fn index(area: &Cuboid<2>, (x, y): Pos) -> usize {
+        let [xs, ys] = area.0;
+        (y - ys.start) as usize * xs.size() + (x - xs.start) as usize
+    }
+
+    /// Reallocates with room for `pos` and as much again as there was along
+    /// the axes it grew, so growing one cell at a time stays cheap
+    fn grow(&mut self, pos: Pos) {
+        let area = match self.area {
+            None => span(None, pos),
+            Some(Cuboid(mut axes)) => {
+                for (axis, v) in axes.iter_mut().zip([pos.0, pos.1]) {
+                    let size = axis.size() as isize;
+                    if v < axis.start {
+                        axis.start = v - size;
+                    } else if v > axis.end {
+                        axis.end = v + size;
+                    }
+                }
+                Cuboid(axes)
+            }
+        };
+
+        let mut cells = vec![self.default.clone(); area.volume()];
+        if let Some(old) = self.area {
+            for (pos, value) in positions(old).zip(std::mem::take(&mut self.cells)) {
+                cells[Self::index(&area, pos)] = value;
+            }
+        }
+        self.area = Some(area);
+        self.cells = cells;
+    }
+}
+
+impl<T: Clone> Grid<T> for InfiniteGrid<T> {
+    fn get(&self, pos: Pos) -> &T {
+        match (self.area, self.bounds) {
+            (Some(area), Some(bounds)) if bounds.contains([pos.0, pos.1]) => {
+                &self.cells[Self::index(&area, pos)]
+            }
+            _ => &self.default,
+        }
+    }
+
+    fn get_mut(&mut self, pos: Pos) -> &mut T {
+        if !matches!(self.area, Some(area) if area.contains([pos.0, pos.1])) {
+            self.grow(pos);
+        }
+        let area = self.area.unwrap();
+
+        let bounds = span(self.bounds, pos);
+        if self.bounds != Some(bounds) {
+            // cells joining the bounds may still hold an older default
+            let joined = match self.bounds {
+                Some(old) => bounds.difference(&old),
+                None => vec![bounds],
+            };
+            for e in joined.into_iter().flat_map(positions) {
+                self.cells[Self::index(&area, e)] = self.default.clone();
+            }
+            self.bounds = Some(bounds);
+        }
+
+        &mut self.cells[Self::index(&area, pos)]
+    }
+
+    fn bounds(&self) -> Option<Cuboid<2>> {
+        self.bounds
+    }
+
+    fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    fn set_default(&mut self, value: T) {
+        self.default = value;
+    }
+}
+
+mod tests {
+    #[test]
+    fn grids_agree() {
+        use super::*;
+
+        let writes = [(2, 1, 5), (0, 0, 1), (3, 3, 2), (2, 1, 7), (1, 2, 4)];
+        let mut dense = DenseGrid::new(4, 4, 0);
+        let mut sparse = SparseGrid::new(0);
+        let mut infinite = InfiniteGrid::new(0);
+        for (x, y, value) in writes {
+            dense.set((x, y), value);
+            sparse.set((x, y), value);
+            *infinite.get_mut((x, y)) += value;
+        }
+        infinite.set((2, 1), 7);
+
+        for pos in positions(Cuboid([Interval::new(-2, 5); 2])) {
+            assert_eq!(dense.get(pos), sparse.get(pos));
+            assert_eq!(dense.get(pos), infinite.get(pos));
+        }
+        assert_eq!(dense.bounds(), sparse.bounds());
+        assert_eq!(dense.bounds(), infinite.bounds());
+        assert_eq!(4, sparse.count(|&e| e > 0));
+        assert_eq!(12, sparse.count(|&e| e == 0));
+        assert_eq!(12, infinite.count(|&e| e == 0));
+
+        let area = dense.bounds().unwrap();
+        assert_eq!(
+            "1...\n..7.\n.4..\n...2",
+            dense.draw(area, |&e| match e {
+                0 => '.',
+                _ => char::from_digit(e, 10).unwrap(),
+            })
+        );
+        assert_eq!(
+            DenseGrid::from_rows(vec![vec![1, 0], vec![0, 2]], 0).get((1, 1)),
+            &2
+        );
+    }
+
+    #[test]
+    fn default_outside_the_stored_cells() {
+        use super::*;
+
+        let mut grid = InfiniteGrid::new(false);
+        grid.set((-3, 10), true);
+        grid.set_default(true);
+        assert!(*grid.get((100, -100)));
+
+        // cells allocated while the default was false are read as the new one
+        grid.set((-2, 10), false);
+        grid.set((-1, 12), false);
+        assert!(*grid.get((-2, 12)));
+        assert!(!*grid.get((-1, 12)));
+        assert_eq!(
+            Some(Cuboid([Interval::new(-3, -1), Interval::new(10, 12)])),
+            grid.bounds()
+        );
+        assert_eq!(7, grid.count(|&e| e));
+
+        let mut sparse = SparseGrid::new('.');
+        sparse.set((-5, -5), '#');
+        sparse.set((5, 5), '#');
+        assert_eq!(2, sparse.iter().count());
+        assert_eq!(2, sparse.count(|&e| e == '#'));
+        assert_eq!(121 - 2, sparse.count(|&e| e == '.'));
+    }
+}
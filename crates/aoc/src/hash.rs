@@ -0,0 +1,118 @@
+//! A fast hasher for the small integer keys most days use.
+//!
+//! std's `HashMap` hashes with SipHash, which resists keys picked to collide
+//! but takes a while on a `(u8, u8)`. Puzzle inputs are trusted, so the maps
+//! here use the add and multiply hash of rustc, a couple of instructions per
+//! word of key.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
+
+const SEED: u64 = 0xf1_35_7a_ea_2e_62_a9_c5;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.hash = self.hash.wrapping_add(word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut words = bytes.chunks_exact(8);
+        for word in &mut words {
+            self.add(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+
+        let rest = words.remainder();
+        if !rest.is_empty() {
+            let mut word = [0; 8];
+            word[..rest.len()].copy_from_slice(rest);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    /// Only the high bits of a product mix in all bits of the key, and the
+    /// map picks buckets with the low bits
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash.rotate_left(26)
+    }
+}
+
+#[cfg(not(feature = "std-hash"))]
+pub type FastBuildHasher = std::hash::BuildHasherDefault<FxHasher>;
+
+/// SipHash again, so the benches can measure what [`FxHasher`] gains the days
+#[cfg(feature = "std-hash")]
+pub type FastBuildHasher = std::collections::hash_map::RandomState;
+
+/// A `HashMap` using [`FxHasher`], made with `FastHashMap::default()`
+pub type FastHashMap<K, V> = HashMap<K, V, FastBuildHasher>;
+
+pub type FastHashSet<T> = HashSet<T, FastBuildHasher>;
+
+mod tests {
+    #[test]
+    fn small_keys_dont_collide() {
+        use super::*;
+        use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+        let hash = |key: &dyn Fn(&mut FxHasher)| {
+            let mut hasher = BuildHasherDefault::<FxHasher>::default().build_hasher();
+            key(&mut hasher);
+            hasher.finish()
+        };
+
+        let mut seen = FastHashSet::default();
+        for a in b'A'..=b'Z' {
+            for b in b'A'..=b'Z' {
+                assert!(seen.insert(hash(&|h| (a, b).hash(h))));
+            }
+        }
+        for key in 0..10_000u16 {
+            assert!(seen.insert(hash(&|h| [1u16, key].hash(h))));
+        }
+
+        // byte slices are hashed a word at a time, with the tail padded
+        assert_ne!(
+            hash(&|h| h.write(b"abcdefghi")),
+            hash(&|h| h.write(b"abcdefgh"))
+        );
+        assert_ne!(hash(&|h| "start".hash(h)), hash(&|h| "end".hash(h)));
+
+        let mut map: FastHashMap<(u8, u8), usize> = FastHashMap::default();
+        *map.entry((b'N', b'N')).or_insert(0) += 2;
+        assert_eq!(Some(&2), map.get(&(b'N', b'N')));
+    }
+}
@@ -0,0 +1,323 @@
+//! Puzzle inputs live in `resources/<year>/dayN.txt`, examples in
+//! `resources/<year>/dayN_test.txt` with the expected answers on the first line.
+//!
+//! Every teammate's input is a profile in `resources/<year>/<profile>/dayN.txt`,
+//! the inputs directly in the year directory are the `default` profile. Known
+//! answers for a profile are kept next to its input in `dayN_answer.txt`.
+//!
+//! Inputs can also be stored encrypted as `dayN.txt.enc`, see [`crate::crypt`].
+//!
+//! With the `embed-inputs` feature the resources directory is built into the
+//! library, and files missing on disk are read from the embedded copy instead.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    // `FILES`, the path of every file relative to the resources and its content
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+
+pub const RESOURCES: &str = "./resources";
+
+/// Images the plots read and write
+pub const ASSETS: &str = "./assets";
+
+static RESOURCES_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+static ASSETS_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Moves the resources away from `RESOURCES`, for the whole process
+pub fn set_resources_dir(dir: PathBuf) {
+    *RESOURCES_DIR.write().unwrap() = Some(dir);
+}
+
+pub fn resources_dir() -> PathBuf {
+    RESOURCES_DIR
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(RESOURCES))
+}
+
+/// Moves the assets away from `ASSETS`, for the whole process
+pub fn set_assets_dir(dir: PathBuf) {
+    *ASSETS_DIR.write().unwrap() = Some(dir);
+}
+
+pub fn assets_dir() -> PathBuf {
+    ASSETS_DIR
+        .read()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(ASSETS))
+}
+
+/// The embedded copy of the file at `path`, if it is in the resources
+#[cfg(feature = "embed-inputs")]
+fn embedded(path: &Path) -> Option<&'static [u8]> {
+    let relative = path.strip_prefix(resources_dir()).ok()?;
+    let name = relative.to_str()?.replace('\\', "/");
+    embedded::FILES
+        .iter()
+        .find(|(e, _)| *e == name)
+        .map(|&(_, content)| content)
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded(_path: &Path) -> Option<&'static [u8]> {
+    None
+}
+
+/// Names of the embedded directories right under `dir`
+#[cfg(feature = "embed-inputs")]
+fn embedded_dirs(dir: &Path) -> Vec<String> {
+    let prefix = match dir
+        .strip_prefix(resources_dir())
+        .ok()
+        .and_then(Path::to_str)
+    {
+        Some("") => String::new(),
+        Some(relative) => format!("{}/", relative.replace('\\', "/")),
+        None => return Vec::new(),
+    };
+    embedded::FILES
+        .iter()
+        .filter_map(|(e, _)| e.strip_prefix(&prefix)?.split_once('/'))
+        .map(|(dir, _)| dir.to_string())
+        .collect()
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded_dirs(_dir: &Path) -> Vec<String> {
+    Vec::new()
+}
+
+/// True if the file is on disk or embedded
+pub fn file_exists(path: &Path) -> bool {
+    path.exists() || embedded(path).is_some()
+}
+
+/// The content of a file on disk, or of its embedded copy
+pub fn read_bytes(path: &Path) -> Result<Cow<'static, [u8]>, String> {
+    if !path.exists() {
+        if let Some(content) = embedded(path) {
+            return Ok(Cow::Borrowed(content));
+        }
+    }
+    std::fs::read(path)
+        .map(Cow::Owned)
+        .map_err(|e| format!("Couldnt read {}: {}", path.display(), e))
+}
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+fn profile_dir(year: usize, profile: &str) -> PathBuf {
+    let dir = resources_dir().join(year.to_string());
+    if profile == DEFAULT_PROFILE {
+        dir
+    } else {
+        dir.join(profile)
+    }
+}
+
+pub fn input_path(year: usize, profile: &str, day: usize) -> PathBuf {
+    profile_dir(year, profile).join(format!("day{}.txt", day))
+}
+
+/// Where the encrypted version of the input at `path` lives
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(crate::crypt::EXTENSION);
+    PathBuf::from(name)
+}
+
+/// True if the input exists in plain text or encrypted
+pub fn input_exists(path: &Path) -> bool {
+    file_exists(path) || file_exists(&encrypted_path(path))
+}
+
+pub fn answer_path(year: usize, profile: &str, day: usize) -> PathBuf {
+    profile_dir(year, profile).join(format!("day{}_answer.txt", day))
+}
+
+/// Profiles with an input for the day, `default` first and the rest sorted by name.
+pub fn profiles(year: usize, day: usize) -> Vec<String> {
+    let dir = profile_dir(year, DEFAULT_PROFILE);
+    let mut profiles: Vec<String> = std::fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .chain(embedded_dirs(&dir))
+        .filter(|profile| input_exists(&input_path(year, profile, day)))
+        .collect();
+    profiles.sort();
+    profiles.dedup();
+
+    if input_exists(&input_path(year, DEFAULT_PROFILE, day)) {
+        profiles.insert(0, DEFAULT_PROFILE.to_string());
+    }
+    profiles
+}
+
+pub fn test_path(year: usize, day: usize) -> PathBuf {
+    resources_dir()
+        .join(year.to_string())
+        .join(format!("day{}_test.txt", day))
+}
+
+pub fn read_file(path: &Path) -> Result<Vec<String>, String> {
+    if !file_exists(path) {
+        return Err(format!("Couldnt find file at path: {}", path.display()));
+    }
+    let content = read_bytes(path)?;
+    let content = std::str::from_utf8(&content)
+        .map_err(|_| format!("{} is not valid UTF-8", path.display()))?;
+    Ok(content.lines().map(|e| e.to_string()).collect())
+}
+
+/// Reads an input, decrypting the encrypted version when there is one. A plain
+/// text copy next to it is an error, neither of them silently wins.
+pub fn read_input(path: &Path) -> Result<Vec<String>, String> {
+    let encrypted = encrypted_path(path);
+    if !file_exists(&encrypted) {
+        return read_file(path);
+    }
+    if file_exists(path) {
+        return Err(format!(
+            "Both {} and {} exist, delete one of them",
+            path.display(),
+            encrypted.display()
+        ));
+    }
+    let content = crate::crypt::read_encrypted(&encrypted)?;
+    Ok(content.lines().map(|e| e.to_string()).collect())
+}
+
+pub fn get_input(year: usize, profile: &str, day: usize) -> Result<Vec<String>, String> {
+    let path = input_path(year, profile, day);
+    if !input_exists(&path) {
+        return Err(format!(
+            "Couldnt find puzzle input for {} day {} in profile {}",
+            year, day, profile
+        ));
+    }
+    read_input(&path)
+}
+
+/// Encrypts every plain text input of the year, in all profiles, and removes
+/// the plain text files. Returns the inputs that were encrypted.
+pub fn encrypt_inputs(year: usize, key: &[u8; 32]) -> Result<Vec<PathBuf>, String> {
+    let root = profile_dir(year, DEFAULT_PROFILE);
+    let dirs = std::iter::once(root.clone()).chain(
+        std::fs::read_dir(&root)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+            .filter(|path| path.is_dir()),
+    );
+
+    let mut encrypted = Vec::new();
+    for dir in dirs {
+        for day in 1..=25 {
+            let path = dir.join(format!("day{}.txt", day));
+            if !path.exists() {
+                continue;
+            }
+
+            let plain = std::fs::read(&path)
+                .map_err(|e| format!("Couldnt read {}: {}", path.display(), e))?;
+            let out = encrypted_path(&path);
+            std::fs::write(&out, crate::crypt::encrypt(key, &plain))
+                .map_err(|e| format!("Couldnt write {}: {}", out.display(), e))?;
+            std::fs::remove_file(&path)
+                .map_err(|e| format!("Couldnt remove {}: {}", path.display(), e))?;
+            encrypted.push(out);
+        }
+    }
+    Ok(encrypted)
+}
+
+/// Recorded answers for the profile's input, `None` if nothing was recorded yet.
+pub fn get_answers(
+    year: usize,
+    profile: &str,
+    day: usize,
+) -> Result<Option<(usize, usize)>, String> {
+    let path = answer_path(year, profile, day);
+    if !file_exists(&path) {
+        return Ok(None);
+    }
+
+    let lines = read_file(&path)?;
+    let answers = lines
+        .get(0)
+        .and_then(|e| e.split_once(" "))
+        .and_then(|(p1, p2)| Some((p1.parse::<usize>().ok()?, p2.parse::<usize>().ok()?)));
+    match answers {
+        Some(answers) => Ok(Some(answers)),
+        None => Err(format!("Expected \"p1 p2\" in {}", path.display())),
+    }
+}
+
+pub fn record_answers(
+    year: usize,
+    profile: &str,
+    day: usize,
+    answers: (usize, usize),
+) -> Result<(), String> {
+    let path = answer_path(year, profile, day);
+    std::fs::write(&path, format!("{} {}\n", answers.0, answers.1))
+        .map_err(|e| format!("Couldnt write {}: {}", path.display(), e))
+}
+
+pub struct TestData {
+    pub input: Vec<String>,
+    pub answer_p1: usize,
+    pub answer_p2: usize,
+}
+
+pub fn get_test_data(year: usize, day: usize) -> Result<TestData, String> {
+    let lines = read_file(&test_path(year, day))
+        .map_err(|_| format!("Couldnt find test input for {} day {}", year, day))?;
+
+    let (p1, p2) = lines.get(0).and_then(|e| e.split_once(" ")).ok_or(format!(
+        "Missing answers in test input for {} day {}",
+        year, day
+    ))?;
+    let answer = |p: &str| {
+        p.parse::<usize>()
+            .map_err(|_| format!("Bad answer {} in test input for {} day {}", p, year, day))
+    };
+
+    Ok(TestData {
+        answer_p1: answer(p1)?,
+        answer_p2: answer(p2)?,
+        input: lines.iter().skip(2).map(|e| e.to_string()).collect(),
+    })
+}
+
+mod tests {
+    #[test]
+    #[cfg(feature = "embed-inputs")]
+    fn reads_embedded_resources() {
+        use super::*;
+
+        let path = test_path(2021, 1);
+        assert_eq!(
+            &include_bytes!("../../../resources/2021/day1_test.txt")[..],
+            embedded(&path).unwrap()
+        );
+        assert!(embedded(&test_path(2021, 26)).is_none());
+
+        // the tests run in the crate directory, away from the resources
+        let data = get_test_data(2021, 1).unwrap();
+        assert_eq!((7, 5), (data.answer_p1, data.answer_p2));
+        assert!(profiles(2021, 1).contains(&DEFAULT_PROFILE.to_string()));
+    }
+}
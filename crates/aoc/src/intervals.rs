@@ -0,0 +1,278 @@
+//! Inclusive integer ranges and the boxes made of them, for days that work
+//! with areas and volumes instead of single points.
+//!
+//! [`CuboidSet`] keeps a union of boxes as disjoint pieces, so adding and
+//! removing boxes never counts a point twice.
+
+/// Every integer from `start` to `end`, both included
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: isize,
+    pub end: isize,
+}
+
+impl Interval {
+    pub fn new(start: isize, end: isize) -> Interval {
+        assert!(start <= end, "interval {}..={} is empty", start, end);
+        Interval { start, end }
+    }
+
+    /// The smallest interval holding every value, `None` without values
+    pub fn spanning(values: impl IntoIterator<Item = isize>) -> Option<Interval> {
+        values.into_iter().fold(None, |span, v| match span {
+            None => Some(Interval::new(v, v)),
+            Some(span) => Some(Interval::new(span.start.min(v), span.end.max(v))),
+        })
+    }
+
+    /// Number of values in the interval
+    pub fn size(&self) -> usize {
+        (self.end - self.start + 1) as usize
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// The values of `self` not in `other`, below and above it
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let mut pieces = Vec::new();
+        if self.start < other.start {
+            pieces.push(Interval::new(self.start, other.start - 1));
+        }
+        if other.end < self.end {
+            pieces.push(Interval::new(other.end + 1, self.end));
+        }
+        pieces
+    }
+}
+
+/// A box with an interval along each of its `N` axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize>(pub [Interval; N]);
+
+impl<const N: usize> Cuboid<N> {
+    /// Number of points inside
+    pub fn volume(&self) -> usize {
+        self.0.iter().map(Interval::size).product()
+    }
+
+    pub fn contains(&self, point: [isize; N]) -> bool {
+        self.0.iter().zip(point).all(|(axis, v)| axis.contains(v))
+    }
+
+    pub fn overlaps(&self, other: &Cuboid<N>) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a.overlaps(b))
+    }
+
+    pub fn intersection(&self, other: &Cuboid<N>) -> Option<Cuboid<N>> {
+        let mut axes = self.0;
+        for (axis, other) in axes.iter_mut().zip(&other.0) {
+            *axis = axis.intersection(other)?;
+        }
+        Some(Cuboid(axes))
+    }
+
+    /// Disjoint boxes covering the points of `self` not in `other`.
+    ///
+    /// Cuts off the slabs outside `other` one axis at a time, at most two per
+    /// axis, each later axis is cut from what is left of the earlier ones.
+    pub fn difference(&self, other: &Cuboid<N>) -> Vec<Cuboid<N>> {
+        let overlap = match self.intersection(other) {
+            Some(overlap) => overlap,
+            None => return vec![*self],
+        };
+
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            for slab in rest.0[axis].difference(&overlap.0[axis]) {
+                let mut piece = rest;
+                piece.0[axis] = slab;
+                pieces.push(piece);
+            }
+            rest.0[axis] = overlap.0[axis];
+        }
+        pieces
+    }
+}
+
+/// A union of boxes, stored as boxes that don't overlap
+#[derive(Debug, Clone, Default)]
+pub struct CuboidSet<const N: usize> {
+    cuboids: Vec<Cuboid<N>>,
+}
+
+impl<const N: usize> CuboidSet<N> {
+    pub fn new() -> CuboidSet<N> {
+        CuboidSet {
+            cuboids: Vec::new(),
+        }
+    }
+
+    pub fn insert(&mut self, cuboid: Cuboid<N>) {
+        self.remove(&cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid<N>) {
+        let mut kept = Vec::with_capacity(self.cuboids.len());
+        for c in &self.cuboids {
+            if c.overlaps(cuboid) {
+                kept.extend(c.difference(cuboid));
+            } else {
+                kept.push(*c);
+            }
+        }
+        self.cuboids = kept;
+    }
+
+    pub fn contains(&self, point: [isize; N]) -> bool {
+        self.cuboids.iter().any(|c| c.contains(point))
+    }
+
+    /// Number of points in the union
+    pub fn volume(&self) -> usize {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    /// The disjoint pieces of the union
+    pub fn iter(&self) -> impl Iterator<Item = &Cuboid<N>> {
+        self.cuboids.iter()
+    }
+}
+
+mod tests {
+    #[test]
+    fn interval_ops() {
+        use super::*;
+
+        let a = Interval::new(2, 8);
+        assert_eq!(7, a.size());
+        assert!(a.contains(2) && a.contains(8) && !a.contains(9));
+
+        assert_eq!(
+            Some(Interval::new(5, 8)),
+            a.intersection(&Interval::new(5, 12))
+        );
+        assert_eq!(
+            Some(Interval::new(8, 8)),
+            a.intersection(&Interval::new(8, 8))
+        );
+        assert_eq!(None, a.intersection(&Interval::new(9, 12)));
+        assert!(!a.overlaps(&Interval::new(-3, 1)));
+
+        assert_eq!(
+            vec![Interval::new(2, 3), Interval::new(7, 8)],
+            a.difference(&Interval::new(4, 6))
+        );
+        assert_eq!(
+            vec![Interval::new(2, 4)],
+            a.difference(&Interval::new(5, 20))
+        );
+        assert_eq!(vec![a], a.difference(&Interval::new(20, 30)));
+        assert!(a.difference(&Interval::new(0, 10)).is_empty());
+
+        assert_eq!(
+            Some(Interval::new(-4, 9)),
+            Interval::spanning([3, -4, 9, 0])
+        );
+        assert_eq!(None, Interval::spanning([]));
+    }
+
+    #[test]
+    fn cuboid_difference_is_disjoint() {
+        use super::*;
+
+        let cube = |lo: isize, hi: isize| Cuboid([Interval::new(lo, hi); 3]);
+        let (a, b) = (cube(10, 12), cube(11, 13));
+
+        assert_eq!(27, a.volume());
+        assert_eq!(Some(cube(11, 12)), a.intersection(&b));
+        assert!(a.contains([10, 12, 11]) && !a.contains([10, 13, 11]));
+        assert_eq!(None, a.intersection(&cube(13, 16)));
+
+        let pieces = a.difference(&b);
+        assert_eq!(27 - 8, pieces.iter().map(Cuboid::volume).sum::<usize>());
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(!p.overlaps(&b));
+            assert!(pieces[i + 1..].iter().all(|q| !p.overlaps(q)));
+        }
+
+        // a hole in the middle leaves a slab on both sides of every axis
+        let pieces = cube(0, 2).difference(&cube(1, 1));
+        assert_eq!(6, pieces.len());
+        assert_eq!(26, pieces.iter().map(Cuboid::volume).sum::<usize>());
+        assert!(cube(0, 2).difference(&cube(-5, 5)).is_empty());
+    }
+
+    #[test]
+    fn set_counts_points_once() {
+        use super::*;
+
+        let square = |x: isize, y: isize, size: isize| {
+            Cuboid([
+                Interval::new(x, x + size - 1),
+                Interval::new(y, y + size - 1),
+            ])
+        };
+
+        let mut set = CuboidSet::new();
+        set.insert(square(0, 0, 4));
+        set.insert(square(2, 2, 4));
+        assert_eq!(16 + 16 - 4, set.volume());
+        set.insert(square(1, 1, 2));
+        assert_eq!(28, set.volume());
+
+        set.remove(&square(0, 0, 6));
+        assert_eq!(0, set.volume());
+
+        set.insert(square(0, 0, 3));
+        set.remove(&square(1, 1, 1));
+        assert_eq!(8, set.volume());
+        assert!(!set.contains([1, 1]) && set.contains([2, 2]));
+
+        // checked against counting every point
+        let boxes = [
+            (0, 0, 5, true),
+            (3, 1, 4, true),
+            (2, 2, 2, false),
+            (4, 4, 3, true),
+        ];
+        let mut set = CuboidSet::new();
+        let mut points = std::collections::HashSet::new();
+        for (x, y, size, on) in boxes {
+            let b = square(x, y, size);
+            if on {
+                set.insert(b);
+            } else {
+                set.remove(&b);
+            }
+            for px in x..x + size {
+                for py in y..y + size {
+                    if on {
+                        points.insert((px, py));
+                    } else {
+                        points.remove(&(px, py));
+                    }
+                }
+            }
+        }
+        assert_eq!(points.len(), set.volume());
+    }
+}
@@ -1,26 +1,22 @@
 #![feature(destructuring_assignment)]
 
+pub mod cancel;
+pub mod crypt;
+pub mod diagnostic;
+pub mod differential;
+pub mod ffi;
+pub mod generate;
+pub mod grid;
+pub mod hash;
+pub mod input;
+pub mod intervals;
+pub mod plugin;
 pub mod runner;
+pub mod sim;
+mod solve;
+pub mod y2021;
 
-pub mod aoc1;
-pub mod aoc2;
-pub mod aoc3;
-pub mod aoc4;
-pub mod aoc5;
-pub mod aoc6;
-pub mod aoc7;
-pub mod aoc8;
-pub mod aoc9;
-pub mod aoc10;
-pub mod aoc11;
-pub mod aoc12;
-pub mod aoc13;
-pub mod aoc14;
-pub mod aoc15;
-pub mod aoc16;
-pub mod aoc17;
-pub mod aoc18;
-pub mod aoc19;
-pub mod aoc20;
-pub mod aoc21;
-pub mod aoc22;
\ No newline at end of file
+pub use runner::PartStatus;
+pub use solve::{
+    available_days, available_days_in, solve, solve_within, solve_year, status, Answer, Error,
+};
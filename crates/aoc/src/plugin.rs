@@ -0,0 +1,218 @@
+//! Solvers loaded from shared libraries, for trying another solution to a day
+//! without changing this crate.
+//!
+//! A plugin is a `cdylib` depending on this crate that calls
+//! [`export_plugin!`](crate::export_plugin) with a function registering its
+//! solvers. Trait objects cross the library boundary, so a plugin has to be
+//! built with the same compiler and version of this crate as the binary, the
+//! declaration it exports is checked for that before anything else is used.
+//!
+//! ```ignore
+//! fn register(registrar: &mut aoc::plugin::Registrar) {
+//!     registrar.register(2021, 1, "windows", || Box::new(Windows::default()));
+//! }
+//!
+//! aoc::export_plugin!(register);
+//! ```
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use libloading::Library;
+
+use crate::runner::Runner;
+
+/// Bumped whenever `Declaration`, `Registrar` or `Runner` change
+pub const API_VERSION: u32 = 1;
+
+pub const AOC_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Directory plugins are loaded from when none is configured
+pub const PLUGINS: &str = "./plugins";
+
+const SYMBOL: &[u8] = b"AOC_PLUGIN";
+
+pub type Factory = fn() -> Box<dyn Runner>;
+
+/// What a plugin exports as `AOC_PLUGIN`, made by [`export_plugin!`](crate::export_plugin)
+#[repr(C)]
+pub struct Declaration {
+    /// First so it can be read before trusting the rest of the layout
+    pub api_version: u32,
+    pub aoc_version: &'static str,
+    pub register: fn(&mut Registrar),
+}
+
+#[derive(Debug, Clone)]
+pub struct Solver {
+    pub year: usize,
+    pub day: usize,
+    pub name: String,
+    /// Library the solver came from
+    pub path: PathBuf,
+    factory: Factory,
+}
+
+/// Collects the solvers of one plugin
+pub struct Registrar {
+    path: PathBuf,
+    solvers: Vec<Solver>,
+}
+
+impl Registrar {
+    pub fn register(&mut self, year: usize, day: usize, name: &str, factory: Factory) {
+        self.solvers.push(Solver {
+            year,
+            day,
+            name: name.to_string(),
+            path: self.path.clone(),
+            factory,
+        });
+    }
+}
+
+#[macro_export]
+macro_rules! export_plugin {
+    ($register:expr) => {
+        #[no_mangle]
+        pub static AOC_PLUGIN: $crate::plugin::Declaration = $crate::plugin::Declaration {
+            api_version: $crate::plugin::API_VERSION,
+            aoc_version: $crate::plugin::AOC_VERSION,
+            register: $register,
+        };
+    };
+}
+
+static SOLVERS: RwLock<Vec<Solver>> = RwLock::new(Vec::new());
+
+/// Checks the declaration and collects the solvers it registers
+fn register(path: &Path, declaration: &Declaration) -> Result<Vec<Solver>, String> {
+    if declaration.api_version != API_VERSION {
+        return Err(format!(
+            "{} uses plugin API {}, expected {}",
+            path.display(),
+            declaration.api_version,
+            API_VERSION
+        ));
+    }
+    if declaration.aoc_version != AOC_VERSION {
+        return Err(format!(
+            "{} is built against aoc {}, expected {}",
+            path.display(),
+            declaration.aoc_version,
+            AOC_VERSION
+        ));
+    }
+
+    let mut registrar = Registrar {
+        path: path.to_path_buf(),
+        solvers: Vec::new(),
+    };
+    (declaration.register)(&mut registrar);
+    Ok(registrar.solvers)
+}
+
+/// Loads the plugin at `path` and returns the solvers it added.
+///
+/// The library is never unloaded, runners made by it may live until the end
+/// of the program.
+pub fn load(path: &Path) -> Result<Vec<Solver>, String> {
+    let solvers = unsafe {
+        let library =
+            Library::new(path).map_err(|e| format!("Couldnt load {}: {}", path.display(), e))?;
+        let declaration = library
+            .get::<*const Declaration>(SYMBOL)
+            .map_err(|_| format!("{} does not export a plugin", path.display()))?;
+        let solvers = register(path, &**declaration)?;
+        std::mem::forget(library);
+        solvers
+    };
+
+    let mut loaded = SOLVERS.write().unwrap();
+    for solver in &solvers {
+        if let Some(other) = loaded
+            .iter()
+            .find(|e| (e.year, e.day, &e.name) == (solver.year, solver.day, &solver.name))
+        {
+            return Err(format!(
+                "{} registers {} for {} day {}, which {} already did",
+                path.display(),
+                solver.name,
+                solver.year,
+                solver.day,
+                other.path.display()
+            ));
+        }
+    }
+    loaded.extend(solvers.iter().cloned());
+    Ok(solvers)
+}
+
+/// Loads every shared library in `dir`, a missing directory has no plugins.
+pub fn load_dir(dir: &Path) -> Vec<(PathBuf, Result<Vec<Solver>, String>)> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|e| e.extension() == Some(OsStr::new(std::env::consts::DLL_EXTENSION)))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let result = load(&path);
+            (path, result)
+        })
+        .collect()
+}
+
+/// Every loaded solver, by year and day
+pub fn solvers() -> Vec<Solver> {
+    let mut solvers = SOLVERS.read().unwrap().clone();
+    solvers.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+    solvers
+}
+
+/// Runner of the plugin solver called `name` for the day
+pub fn get_runner(year: usize, day: usize, name: &str) -> Option<Box<dyn Runner>> {
+    let solvers = SOLVERS.read().unwrap();
+    let solver = solvers
+        .iter()
+        .find(|e| e.year == year && e.day == day && e.name == name)?;
+    Some(crate::runner::traced(year, day, (solver.factory)()))
+}
+
+mod tests {
+    #[test]
+    fn declaration_is_checked() {
+        use super::*;
+
+        fn day1(registrar: &mut Registrar) {
+            registrar.register(2021, 1, "builtin", || {
+                Box::new(crate::y2021::aoc1::AOC1::default())
+            });
+        }
+        let mut declaration = Declaration {
+            api_version: API_VERSION,
+            aoc_version: AOC_VERSION,
+            register: day1,
+        };
+
+        let solvers = register(Path::new("day1.so"), &declaration).unwrap();
+        assert_eq!(1, solvers.len());
+        assert_eq!("builtin", solvers[0].name);
+        assert_eq!(Path::new("day1.so"), solvers[0].path);
+
+        declaration.aoc_version = "0.0.0";
+        assert!(register(Path::new("day1.so"), &declaration).is_err());
+        declaration.api_version = API_VERSION + 1;
+        assert!(register(Path::new("day1.so"), &declaration)
+            .unwrap_err()
+            .contains("plugin API"));
+    }
+}
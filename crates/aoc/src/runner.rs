@@ -1,6 +1,219 @@
+use std::fmt::Display;
+
+use crate::diagnostic::ParseError;
+use crate::y2021;
+
+/// How far along a part is, listings show this instead of running parts that
+/// cannot give an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// Gets the example right but not the puzzle inputs
+    ExampleOnly,
+    /// Returns an answer, but not the one the puzzle asks for
+    Partial,
+    Unimplemented,
+}
+
+impl PartStatus {
+    /// Calendar style marker, a star for a solved part
+    pub fn symbol(&self) -> char {
+        match self {
+            PartStatus::Solved => '*',
+            PartStatus::ExampleOnly => '+',
+            PartStatus::Partial => '~',
+            PartStatus::Unimplemented => '.',
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            PartStatus::Solved => "solved",
+            PartStatus::ExampleOnly => "example only",
+            PartStatus::Partial => "partial",
+            PartStatus::Unimplemented => "unimplemented",
+        })
+    }
+}
 
 pub trait Runner {
-    fn parse(&mut self, input: &Vec<String>);
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError>;
     fn run_p1(&self) -> usize;
     fn run_p2(&self) -> usize;
-}
\ No newline at end of file
+
+    /// Slow but straightforward version of part 1, used to check `run_p1`
+    /// against generated inputs.
+    fn reference_p1(&self) -> Option<usize> {
+        None
+    }
+
+    /// Slow but straightforward version of part 2, used to check `run_p2`
+    /// against generated inputs.
+    fn reference_p2(&self) -> Option<usize> {
+        None
+    }
+
+    /// Status of `part`, parts that are not `Solved` override this.
+    fn status(&self, _part: usize) -> PartStatus {
+        PartStatus::Solved
+    }
+
+    /// Changes a setting of the day, like the number of steps to simulate.
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("Unknown setting {}", key))
+    }
+
+    /// Renders the parsed state for inspection, if the day has something to show.
+    fn show(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Parses the value of a `Runner::set` that expects a number
+pub fn parse_setting(key: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| format!("Expected a number for {}, got {}", key, value))
+}
+
+/// Years that have solvers, oldest first
+pub const YEARS: [usize; 1] = [2021];
+
+/// Year used when none is given
+pub const DEFAULT_YEAR: usize = 2021;
+
+pub fn get_runner(year: usize, day: usize) -> Option<Box<dyn Runner>> {
+    let runner = match year {
+        2021 => y2021::get_runner(day)?,
+        _ => return None,
+    };
+    Some(traced(year, day, runner))
+}
+
+/// Wraps a runner so its phases are traced and parse errors name the day
+pub(crate) fn traced(year: usize, day: usize, runner: Box<dyn Runner>) -> Box<dyn Runner> {
+    Box::new(Traced { year, day, runner })
+}
+
+/// Source of a day's solver, anything that caches answers hashes this so edits
+/// to the solver invalidate them.
+pub fn solver_source(year: usize, day: usize) -> Option<&'static str> {
+    match year {
+        2021 => y2021::solver_source(day),
+        _ => None,
+    }
+}
+
+/// Hash of everything that decides a day's answers, the solver, the modules
+/// it depends on and the crate version. Caches key answers by it.
+pub fn solver_version(year: usize, day: usize) -> Option<u64> {
+    let sources = match year {
+        2021 => y2021::solver_sources(day)?,
+        _ => return None,
+    };
+    Some(version_of(&sources))
+}
+
+fn version_of(sources: &[&str]) -> u64 {
+    let mut bytes = env!("CARGO_PKG_VERSION").as_bytes().to_vec();
+    for source in sources {
+        bytes.push(0);
+        bytes.extend(source.as_bytes());
+    }
+    fnv1a(&bytes)
+}
+
+/// What a day's input looks like, shown when it fails to parse
+pub fn input_format(year: usize, day: usize) -> Option<&'static str> {
+    match year {
+        2021 => y2021::input_format(day),
+        _ => None,
+    }
+}
+
+/// 64 bit FNV-1a, stable across builds and platforms unlike `DefaultHasher`
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Enters a tracing span around every phase of the wrapped runner
+struct Traced {
+    year: usize,
+    day: usize,
+    runner: Box<dyn Runner>,
+}
+
+impl Runner for Traced {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let _span = tracing::info_span!(
+            "parse",
+            year = self.year,
+            day = self.day,
+            lines = input.len()
+        )
+        .entered();
+        self.runner.parse(input).map_err(|mut e| {
+            e.context.day = Some((self.year, self.day));
+            e.context.expected = input_format(self.year, self.day);
+            e
+        })
+    }
+
+    fn run_p1(&self) -> usize {
+        let _span = tracing::info_span!("p1", year = self.year, day = self.day).entered();
+        self.runner.run_p1()
+    }
+
+    fn run_p2(&self) -> usize {
+        let _span = tracing::info_span!("p2", year = self.year, day = self.day).entered();
+        self.runner.run_p2()
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        let _span = tracing::info_span!("reference_p1", year = self.year, day = self.day).entered();
+        self.runner.reference_p1()
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        let _span = tracing::info_span!("reference_p2", year = self.year, day = self.day).entered();
+        self.runner.reference_p2()
+    }
+
+    fn status(&self, part: usize) -> PartStatus {
+        self.runner.status(part)
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        self.runner.set(key, value)
+    }
+
+    fn show(&self) -> Option<String> {
+        self.runner.show()
+    }
+}
+
+mod tests {
+    #[test]
+    fn version_covers_shared_modules() {
+        use super::*;
+
+        let sources = y2021::solver_sources(11).unwrap();
+        let version = solver_version(2021, 11).unwrap();
+        assert_eq!(version, version_of(&sources));
+        assert_ne!(version, solver_version(2021, 12).unwrap());
+        assert!(sources.contains(&include_str!("runner.rs")));
+        assert!(sources.contains(&include_str!("sim.rs")));
+
+        // editing any shared module gives a new key, not only the solver
+        for i in 0..sources.len() {
+            let edited = format!("{}\n", sources[i]);
+            let mut changed: Vec<&str> = sources.clone();
+            changed[i] = &edited;
+            assert_ne!(version, version_of(&changed));
+        }
+    }
+}
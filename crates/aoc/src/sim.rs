@@ -0,0 +1,64 @@
+//! Days that apply the same step over and over, like the lanternfish of day 6
+//! or the octopuses of day 11.
+//!
+//! A day implements [`Simulation`] on its state and gets running a number of
+//! steps and running until a condition holds. Both stop at a cancellation
+//! checkpoint between steps.
+
+use crate::cancel::checkpoint;
+
+pub trait Simulation {
+    /// Advances the state by one step
+    fn step(&mut self);
+
+    /// The number the puzzle asks about in the current state
+    fn observe(&self) -> usize;
+
+    fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            checkpoint();
+            self.step();
+        }
+    }
+
+    /// Steps until `done` holds for the state and returns how many steps that
+    /// took, 0 if it already held.
+    fn run_until(&mut self, mut done: impl FnMut(&Self) -> bool) -> usize
+    where
+        Self: Sized,
+    {
+        let mut steps = 0;
+        while !done(self) {
+            checkpoint();
+            self.step();
+            steps += 1;
+        }
+        steps
+    }
+}
+
+mod tests {
+    #[test]
+    fn run_and_run_until() {
+        use super::*;
+
+        /// Walks 0, 1, 2, .. up to 9 and then around 3..=9
+        struct Walk(usize);
+
+        impl Simulation for Walk {
+            fn step(&mut self) {
+                self.0 = if self.0 == 9 { 3 } else { self.0 + 1 };
+            }
+
+            fn observe(&self) -> usize {
+                self.0
+            }
+        }
+
+        let mut walk = Walk(0);
+        assert_eq!(5, walk.run_until(|e| e.0 == 5));
+        walk.run(6);
+        assert_eq!(4, walk.observe());
+        assert_eq!(0, walk.run_until(|e| e.0 == 4));
+    }
+}
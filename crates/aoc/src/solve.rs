@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use crate::cancel::{with_budget, TimedOut, DEFAULT_BUDGET};
+use crate::diagnostic::ParseError;
+use crate::runner::{get_runner, PartStatus, DEFAULT_YEAR, YEARS};
+
+/// Answer to one part of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Answer(pub usize);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownYear(usize),
+    UnknownDay(usize),
+    UnknownPart(usize),
+    /// The part has no solver yet, holds the day and part
+    Unimplemented(usize, usize),
+    /// The input doesnt match the day's format, `ParseError::render` shows where
+    Parse(ParseError),
+    /// The solver panicked while parsing or solving, most likely on malformed input
+    Panicked(String),
+    /// The solver didnt finish within the budget
+    TimedOut(Duration),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownYear(year) => write!(f, "No solvers for year {}", year),
+            Error::UnknownDay(day) => write!(f, "Runner for day {} not implemented", day),
+            Error::UnknownPart(part) => write!(f, "Part must be 1 or 2, got {}", part),
+            Error::Unimplemented(day, part) => {
+                write!(f, "Day {} part {} is not implemented", day, part)
+            }
+            Error::Parse(e) => write!(f, "Couldnt parse input: {}", e),
+            Error::Panicked(msg) => write!(f, "Solver panicked: {}", msg),
+            Error::TimedOut(budget) => write!(f, "Solver timed out after {:?}", budget),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Days of the default year that have a runner, in ascending order.
+pub fn available_days() -> Vec<usize> {
+    available_days_in(DEFAULT_YEAR)
+}
+
+/// Days of `year` that have a runner, in ascending order.
+pub fn available_days_in(year: usize) -> Vec<usize> {
+    (1..=25)
+        .filter(|&day| get_runner(year, day).is_some())
+        .collect()
+}
+
+/// Status of both parts of `day` in `year`.
+pub fn status(year: usize, day: usize) -> Result<[PartStatus; 2], Error> {
+    if !YEARS.contains(&year) {
+        return Err(Error::UnknownYear(year));
+    }
+    let runner = get_runner(year, day).ok_or(Error::UnknownDay(day))?;
+    Ok([runner.status(1), runner.status(2)])
+}
+
+/// Parses `input` and solves `part` of `day` in the default year.
+pub fn solve(day: usize, part: usize, input: &str) -> Result<Answer, Error> {
+    solve_year(DEFAULT_YEAR, day, part, input)
+}
+
+/// Parses `input` and solves `part` of `day` in `year`, within the default
+/// budget.
+pub fn solve_year(year: usize, day: usize, part: usize, input: &str) -> Result<Answer, Error> {
+    solve_within(year, day, part, input, DEFAULT_BUDGET)
+}
+
+/// Parses `input` and solves `part` of `day` in `year`, giving up with
+/// `Error::TimedOut` once `budget` is spent.
+///
+/// Panics in the solver are caught and returned as `Error::Panicked`, the
+/// panic hook still prints them.
+pub fn solve_within(
+    year: usize,
+    day: usize,
+    part: usize,
+    input: &str,
+    budget: Duration,
+) -> Result<Answer, Error> {
+    if !YEARS.contains(&year) {
+        return Err(Error::UnknownYear(year));
+    }
+    let mut runner = get_runner(year, day).ok_or(Error::UnknownDay(day))?;
+    if part != 1 && part != 2 {
+        return Err(Error::UnknownPart(part));
+    }
+    if runner.status(part) == PartStatus::Unimplemented {
+        return Err(Error::Unimplemented(day, part));
+    }
+
+    let input: Vec<String> = input.lines().map(|e| e.to_string()).collect();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        with_budget(budget, || {
+            runner.parse(&input).map_err(Error::Parse)?;
+            if part == 1 {
+                Ok(runner.run_p1())
+            } else {
+                Ok(runner.run_p2())
+            }
+        })
+    }));
+
+    match answer {
+        Ok(Ok(answer)) => answer.map(Answer),
+        Ok(Err(TimedOut(budget))) => Err(Error::TimedOut(budget)),
+        Err(payload) => {
+            let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+                msg.to_string()
+            } else if let Some(msg) = payload.downcast_ref::<String>() {
+                msg.clone()
+            } else {
+                String::from("unknown panic")
+            };
+            Err(Error::Panicked(msg))
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn solve_from_str() {
+        use super::*;
+
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        assert_eq!(Ok(Answer(7)), solve(1, 1, input));
+        assert_eq!(Ok(Answer(5)), solve(1, 2, input));
+    }
+
+    #[test]
+    fn solve_errors() {
+        use super::*;
+
+        assert_eq!(Err(Error::UnknownDay(26)), solve(26, 1, ""));
+        assert_eq!(Err(Error::UnknownYear(1999)), solve_year(1999, 1, 1, ""));
+        assert_eq!(Err(Error::UnknownPart(3)), solve(1, 3, ""));
+        assert!(matches!(solve(1, 1, "1\nabc"), Err(Error::Parse(e)) if e.context.line == 1));
+        assert!(matches!(solve(3, 1, ""), Err(Error::Parse(_))));
+        assert_eq!(Err(Error::Unimplemented(18, 2)), solve(18, 2, "[1,2]"));
+        assert_eq!(
+            Ok([PartStatus::Solved, PartStatus::Partial]),
+            status(2021, 13)
+        );
+        assert_eq!((1..=22).collect::<Vec<usize>>(), available_days());
+        assert_eq!(
+            Err(Error::TimedOut(Duration::ZERO)),
+            solve_within(2021, 11, 2, "5483143223\n2745854711", Duration::ZERO)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::diagnostic::{lines, ParseError};
+use crate::generate::GenRng;
+use crate::runner::Runner;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC1 {
+    parsed: Vec<i64>,
+}
+
+impl Runner for AOC1 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        self.parsed = lines(input)
+            .map(|l| l.number::<i64>(l.text))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        self.parsed.windows(2).filter(|e| (e[1] - e[0]) > 0).count()
+    }
+    fn run_p2(&self) -> usize {
+        self.parsed
+            .windows(3)
+            .map(|e| e[0] + e[1] + e[2])
+            .collect::<Vec<i64>>()
+            .windows(2)
+            .filter(|e| (e[1] - e[0]) > 0)
+            .count()
+    }
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let mut depth: i64 = rng.gen_range(100..200);
+    (0..size)
+        .map(|_| {
+            depth = (depth + rng.gen_range(-15..=25)).max(0);
+            depth.to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,169 @@
+use crate::diagnostic::{lines, ParseError};
+use crate::generate::GenRng;
+use crate::runner::Runner;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC10 {
+    parsed: Vec<Vec<char>>,
+}
+
+fn to_points_p1(c: char) -> usize {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => unreachable!(),
+    }
+}
+
+fn to_points_p2(c: char) -> usize {
+    match c {
+        '(' => 1,
+        '[' => 2,
+        '{' => 3,
+        '<' => 4,
+        _ => unreachable!(),
+    }
+}
+
+fn is_matching(l: char, r: char) -> bool {
+    match l {
+        '(' if r == ')' => true,
+        '{' if r == '}' => true,
+        '[' if r == ']' => true,
+        '<' if r == '>' => true,
+        _ => false,
+    }
+}
+
+const OPEN: [char; 4] = ['[', '{', '(', '<'];
+
+impl Runner for AOC10 {
+    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) -> Result<(), ParseError> {
+        self.parsed = lines(input)
+            .map(|l| {
+                let bad = l.text.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c));
+                match bad {
+                    Some((i, c)) => Err(l
+                        .error_at(i, 1, format!("expected a bracket, found `{}`", c))
+                        .with_hint("chunks are made of `()`, `[]`, `{}` and `<>`")),
+                    None => Ok(l.text.chars().collect()),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+    fn run_p1(&self) -> usize {
+        let mut postfix = Vec::new();
+        self.parsed
+            .iter()
+            .map(|l| {
+                postfix.clear();
+                l.iter()
+                    .find_map(|c| match OPEN.contains(c) {
+                        true => {
+                            postfix.push(c);
+                            None
+                        }
+                        false => {
+                            (!is_matching(*postfix.pop().unwrap(), *c)).then(|| to_points_p1(*c))
+                        }
+                    })
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+    fn run_p2(&self) -> usize {
+        let mut postfix = Vec::new();
+        let mut lsums: Vec<usize> = self
+            .parsed
+            .iter()
+            .filter_map(|l| {
+                postfix.clear();
+                for c in l {
+                    if OPEN.contains(c) {
+                        postfix.push(*c);
+                    } else {
+                        let e = postfix.pop().unwrap();
+                        if !is_matching(e, *c) {
+                            return None;
+                        }
+                    }
+                }
+                Some(
+                    postfix
+                        .iter()
+                        .rev()
+                        .fold(0usize, |acc, &e| (5 * acc) + to_points_p2(e)),
+                )
+            })
+            .collect();
+
+        lsums.sort_unstable();
+
+        lsums[lsums.len() / 2]
+    }
+}
+
+fn closing(c: char) -> char {
+    match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!(),
+    }
+}
+
+/// Every line is either corrupted or incomplete, with an odd number of
+/// incomplete lines so part 2 has a middle score.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let mut incomplete: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.5)).collect();
+    if incomplete.iter().filter(|&&e| e).count() % 2 == 0 {
+        incomplete[0] = !incomplete[0];
+    }
+
+    incomplete
+        .into_iter()
+        .map(|incomplete| {
+            let len = rng.gen_range(10..100);
+            let corrupt_at = rng.gen_range(1..len);
+            let mut line = String::new();
+            let mut stack = Vec::new();
+
+            for i in 0..len {
+                if !incomplete && i >= corrupt_at && !stack.is_empty() {
+                    break;
+                }
+
+                if stack.is_empty() || (stack.len() < 20 && rng.gen_bool(0.55)) {
+                    let c = OPEN[rng.gen_range(0..OPEN.len())];
+                    stack.push(c);
+                    line.push(c);
+                } else {
+                    line.push(closing(stack.pop().unwrap()));
+                }
+            }
+
+            if incomplete {
+                if stack.is_empty() {
+                    line.push(OPEN[rng.gen_range(0..OPEN.len())]);
+                }
+            } else {
+                let expected = closing(*stack.last().unwrap());
+                let wrong = loop {
+                    let c = closing(OPEN[rng.gen_range(0..OPEN.len())]);
+                    if c != expected {
+                        break c;
+                    }
+                };
+                line.push(wrong);
+            }
+
+            line
+        })
+        .collect()
+}
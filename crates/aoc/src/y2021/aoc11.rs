@@ -1,4 +1,8 @@
+use crate::diagnostic::{digit_grid, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use crate::sim::Simulation;
+use rand::Rng;
 use std::ops::Add;
 
 #[derive(Default)]
@@ -54,6 +58,8 @@ struct Map {
     data: Vec<Cell>,
     w: usize,
     h: usize,
+    /// flashes over all steps so far
+    flashes: usize,
 }
 
 impl Map {
@@ -63,6 +69,9 @@ impl Map {
     fn value_sum(&self) -> usize {
         self.data.iter().map(|e| e.value as usize).sum()
     }
+    fn in_sync(&self) -> bool {
+        self.value_sum() == 0
+    }
     fn neighbours_pos<'a>(&self, point: Point) -> Box<dyn Iterator<Item = Point> + 'a> {
         let (w, h) = (self.w, self.h);
         Box::new(
@@ -74,55 +83,85 @@ impl Map {
 }
 
 impl Runner for AOC11 {
-    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) {
-        let cells: Vec<Cell> = input
+    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) -> Result<(), ParseError> {
+        let grid = digit_grid(input)?;
+        let cells: Vec<Cell> = grid
             .iter()
-            .flat_map(|e| {
-                e.chars()
-                    .map(|c| Cell {
-                        value: (c as u8 - 48),
-                        ..Default::default()
-                    })
-                    .collect::<Vec<Cell>>()
+            .flatten()
+            .map(|&value| Cell {
+                value,
+                ..Default::default()
             })
             .collect();
 
         self.parsed = Map {
-            h: input.len(),
-            w: input[0].len(),
+            h: grid.len(),
+            w: grid[0].len(),
             data: cells,
+            flashes: 0,
         };
+        Ok(())
     }
     fn run_p1(&self) -> usize {
         let mut map = self.parsed.clone();
-        let mut sum = 0;
-
-        for _i in 0..100 {
-            let val = step(&mut map);
-
-            sum += val;
-
-            /* for l in &map.data {
-                println!("{:2?}", l.iter().map(|e| e.value).collect::<Vec<u8>>());
-            }
-            println!(); */
-        }
-
-        sum
+        map.run(100);
+        map.observe()
     }
     fn run_p2(&self) -> usize {
         let mut map = self.parsed.clone();
-        let mut s = 0;
+        // the first step is always taken, a grid of zeros flashes in sync on it
+        map.step();
+        map.run_until(Map::in_sync) + 1
+    }
 
-        for i in 0..10_000 {
-            s = i + 1;
-            let _val = step(&mut map);
-            if map.value_sum() == 0 {
-                break;
-            }
+    fn show(&self) -> Option<String> {
+        let map = &self.parsed;
+        let rows = map.data.chunks(map.w.max(1)).map(|row| {
+            row.iter()
+                .map(|c| (c.value + b'0') as char)
+                .collect::<String>()
+        });
+        Some(rows.collect::<Vec<String>>().join("\n"))
+    }
+}
+
+/// Real inputs always end up flashing in sync, so random grids are retried
+/// until one does within a thousand steps. Part 2 never ends on a grid that
+/// doesn't, so when none of them do the grid is all zeros, which syncs on the
+/// tenth step.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let random_grid = |rng: &mut GenRng| -> Vec<String> {
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| (b'0' + rng.gen_range(0..10)) as char)
+                    .collect()
+            })
+            .collect()
+    };
+
+    for _ in 0..100 {
+        let lines = random_grid(rng);
+        let mut aoc = AOC11::default();
+        aoc.parse(&lines).unwrap();
+        if (0..1000).any(|_| step(&mut aoc.parsed) == size * size) {
+            return lines;
         }
+    }
+
+    vec!["0".repeat(size); size]
+}
+
+impl Simulation for Map {
+    fn step(&mut self) {
+        let flashes = step(self);
+        tracing::trace!(flashes);
+        self.flashes += flashes;
+    }
 
-        s
+    fn observe(&self) -> usize {
+        self.flashes
     }
 }
 
@@ -1,6 +1,9 @@
+use crate::diagnostic::{lines, ParseError};
+use crate::generate::GenRng;
+use crate::hash::{FastHashMap, FastHashSet};
 use crate::runner::Runner;
-use std::collections::HashMap;
-use std::collections::HashSet;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 type NodeIndex = usize;
 type EdgeIndex = usize;
@@ -25,8 +28,8 @@ struct Graph {
     exit: NodeIndex,
     nodes: Vec<Node>,
     edges: Vec<Edge>,
-    edge_lookup: HashMap<NodeIndex, Vec<EdgeIndex>>,
-    node_lookup: HashMap<String, NodeIndex>,
+    edge_lookup: FastHashMap<NodeIndex, Vec<EdgeIndex>>,
+    node_lookup: FastHashMap<String, NodeIndex>,
 }
 
 impl Graph {
@@ -93,11 +96,14 @@ pub struct AOC12 {
 }
 
 impl Runner for AOC12 {
-    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) {
+    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) -> Result<(), ParseError> {
         let mut graph = Graph::default();
 
-        for l in input {
-            let (a, b) = l.split_once('-').unwrap();
+        for l in lines(input) {
+            let (a, b) = l.split_once(l.text, "-")?;
+            if let Some(empty) = [a, b].into_iter().find(|e| e.is_empty()) {
+                return Err(l.error(empty, "expected a cave name, found nothing"));
+            }
             let a_idx = if let Some(idx) = graph.get_node_by_name(a) {
                 *idx
             } else {
@@ -113,6 +119,7 @@ impl Runner for AOC12 {
         }
 
         self.graph = graph;
+        Ok(())
     }
     fn run_p1(&self) -> usize {
         let mut paths = Vec::new();
@@ -120,7 +127,7 @@ impl Runner for AOC12 {
         find_paths_p1(
             &self.graph,
             self.graph.entrance,
-            HashSet::new(),
+            FastHashSet::default(),
             vec![],
             &mut paths,
         );
@@ -134,22 +141,82 @@ impl Runner for AOC12 {
             &self.graph,
             self.graph.entrance,
             0,
-            HashSet::new(),
+            FastHashSet::default(),
             vec![],
             &mut paths,
         );
 
         paths.len()
     }
+
+    fn reference_p1(&self) -> Option<usize> {
+        Some(count_paths_reference(&self.graph, false))
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        Some(count_paths_reference(&self.graph, true))
+    }
+}
+
+/// Enumerates every path by cave name with an explicit stack, looking through
+/// the whole path to decide if a small cave can be entered again.
+fn count_paths_reference(graph: &Graph, allow_twice: bool) -> usize {
+    let names: FastHashMap<NodeIndex, &str> = graph
+        .node_lookup
+        .iter()
+        .map(|(k, &v)| (v, k.as_str()))
+        .collect();
+    let is_small = |name: &str| name.chars().all(|c| c.is_ascii_lowercase());
+
+    let mut count = 0;
+    let mut open = vec![vec!["start"]];
+    while let Some(path) = open.pop() {
+        crate::cancel::checkpoint();
+        let last = *path.last().unwrap();
+        if last == "end" {
+            count += 1;
+            continue;
+        }
+
+        let current = graph.node_lookup[last];
+        for edge in &graph.edges {
+            let next = if edge.node_a == current {
+                names[&edge.node_b]
+            } else if edge.node_b == current {
+                names[&edge.node_a]
+            } else {
+                continue;
+            };
+
+            if next == "start" {
+                continue;
+            }
+            if is_small(next) && path.contains(&next) {
+                let used_twice = path
+                    .iter()
+                    .any(|&a| is_small(a) && path.iter().filter(|&&b| a == b).count() > 1);
+                if !allow_twice || used_twice {
+                    continue;
+                }
+            }
+
+            let mut path = path.clone();
+            path.push(next);
+            open.push(path);
+        }
+    }
+
+    count
 }
 
 fn find_paths_p1(
     graph: &Graph,
     current: NodeIndex,
-    mut visited: HashSet<NodeIndex>,
+    mut visited: FastHashSet<NodeIndex>,
     mut path: Vec<NodeIndex>,
     paths: &mut Vec<Vec<NodeIndex>>,
 ) {
+    crate::cancel::checkpoint();
     path.push(current);
 
     match &graph.nodes[current] {
@@ -183,10 +250,11 @@ fn find_paths_p2(
     graph: &Graph,
     current: NodeIndex,
     mut small_visit_count: u8,
-    mut visited: HashSet<NodeIndex>,
+    mut visited: FastHashSet<NodeIndex>,
     mut path: Vec<NodeIndex>,
     paths: &mut Vec<Vec<NodeIndex>>,
 ) {
+    crate::cancel::checkpoint();
     path.push(current);
 
     match &graph.nodes[current] {
@@ -224,3 +292,55 @@ fn find_paths_p2(
         );
     }
 }
+
+/// Builds a connected cave system with `size` small caves. Big caves are never
+/// connected to each other, otherwise there would be infinitely many paths.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let name = |rng: &mut GenRng, big: bool| {
+        let base = if big { b'A' } else { b'a' };
+        (0..2)
+            .map(|_| (base + rng.gen_range(0..26)) as char)
+            .collect::<String>()
+    };
+
+    let mut caves = vec![("start".to_string(), false)];
+    let big_count = size / 3 + 1;
+    while caves.len() < size.max(1) + big_count + 1 {
+        let big = caves.len() - 1 < big_count;
+        let name = name(rng, big);
+        if !caves.iter().any(|(e, _)| *e == name) {
+            caves.push((name, big));
+        }
+    }
+    caves[1..].shuffle(rng);
+    caves.push(("end".to_string(), false));
+
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    let add_edge = |edges: &mut Vec<(usize, usize)>, a: usize, b: usize| {
+        if a != b
+            && !(caves[a].1 && caves[b].1)
+            && !edges.contains(&(a, b))
+            && !edges.contains(&(b, a))
+        {
+            edges.push((a, b));
+        }
+    };
+
+    // random spanning tree over every cave, then a few extra edges
+    for i in 1..caves.len() {
+        let candidates: Vec<usize> = (0..i).filter(|&j| !(caves[i].1 && caves[j].1)).collect();
+        let j = *candidates.choose(rng).unwrap_or(&0);
+        add_edge(&mut edges, j, i);
+    }
+    for _ in 0..size {
+        let a = rng.gen_range(0..caves.len());
+        let b = rng.gen_range(0..caves.len());
+        add_edge(&mut edges, a, b);
+    }
+
+    edges.shuffle(rng);
+    edges
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", caves[a].0, caves[b].0))
+        .collect()
+}
@@ -0,0 +1,233 @@
+use crate::diagnostic::{line, lines, ParseError};
+use crate::generate::GenRng;
+use crate::grid::{Grid, SparseGrid};
+use crate::intervals::{Cuboid, Interval};
+use crate::runner::{PartStatus, Runner};
+use rand::Rng;
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct AOC13 {
+    folds: Vec<Fold>,
+    dots: Vec<Point>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Fold {
+    AlongX(usize),
+    AlongY(usize),
+}
+
+#[derive(Debug, Default)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Runner for AOC13 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let empty_index = input.iter().position(|e| e == "").unwrap_or(input.len());
+        line(input, empty_index)
+            .map_err(|e| e.with_hint("dots and folds are separated by a blank line"))?;
+        let mut points: Vec<Point> = Vec::new();
+        for l in lines(&input[..empty_index]) {
+            let (x, y) = l.split_once(l.text, ",")?;
+            let (x, y) = (l.number::<usize>(x)?, l.number::<usize>(y)?);
+            points.push(Point { x, y });
+        }
+
+        let folds: Vec<Fold> = lines(input)
+            .skip(empty_index + 1)
+            .map(|l| {
+                let (along, r) = l.split_once(l.text, "=")?;
+                match along {
+                    "fold along y" => Ok(Fold::AlongY(l.number::<usize>(r)?)),
+                    "fold along x" => Ok(Fold::AlongX(l.number::<usize>(r)?)),
+                    _ => Err(l.error(along, "expected `fold along x` or `fold along y`")),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        self.folds = folds;
+        self.dots = points;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        let mut sheet = Sheet::new(&self.dots);
+        sheet.fold(self.folds[0]);
+
+        sheet.dots.count(|&e| e)
+    }
+
+    fn run_p2(&self) -> usize {
+        let mut sheet = Sheet::new(&self.dots);
+
+        for &fold in &self.folds {
+            sheet.fold(fold);
+        }
+
+        0
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        let mirror = |v: usize, line: usize| if v > line { 2 * line - v } else { v };
+        let dots: HashSet<(usize, usize)> = self
+            .dots
+            .iter()
+            .map(|p| match self.folds[0] {
+                Fold::AlongX(line) => (mirror(p.x, line), p.y),
+                Fold::AlongY(line) => (p.x, mirror(p.y, line)),
+            })
+            .collect();
+        Some(dots.len())
+    }
+
+    /// The second answer is the letters on the folded sheet, `show` draws them
+    fn status(&self, part: usize) -> PartStatus {
+        match part {
+            1 => PartStatus::Solved,
+            _ => PartStatus::Partial,
+        }
+    }
+
+    /// Shows the sheet after the last fold
+    fn show(&self) -> Option<String> {
+        let mut sheet = Sheet::new(&self.dots);
+
+        for &fold in &self.folds {
+            sheet.fold(fold);
+        }
+
+        // folding along an edge or losing every dot leaves nothing to draw
+        if sheet.width <= 0 || sheet.height <= 0 || sheet.dots.count(|&e| e) == 0 {
+            return None;
+        }
+        let area = Cuboid([
+            Interval::new(0, sheet.width - 1),
+            Interval::new(0, sheet.height - 1),
+        ]);
+        Some(sheet.dots.draw(area, |&e| if e { '#' } else { '.' }))
+    }
+}
+
+/// Dots are placed on a small final sheet and then unfolded, mirroring each
+/// dot at random, so no dot ever lands on a fold line.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let (final_w, final_h) = (rng.gen_range(5..40usize), rng.gen_range(3..7usize));
+    let fold_count = 5;
+
+    let unfold = |len: usize| {
+        (0..fold_count).fold(vec![len], |mut acc, _| {
+            acc.push(acc.last().unwrap() * 2 + 1);
+            acc
+        })
+    };
+    // sheet sizes from the final size outwards, the fold lines are the sizes before unfolding
+    let widths = unfold(final_w);
+    let heights = unfold(final_h);
+
+    let place = |mut v: usize, sizes: &Vec<usize>, mirrors: &Vec<bool>| {
+        for i in 0..fold_count {
+            if mirrors[i] {
+                v = 2 * sizes[i] - v;
+            }
+        }
+        v
+    };
+
+    let mut dots = HashSet::new();
+    // a dot in the far corner so the sheet has its full size
+    let outer: Vec<bool> = (0..fold_count).map(|i| i == fold_count - 1).collect();
+    dots.insert((place(0, &widths, &outer), place(0, &heights, &outer)));
+
+    while dots.len() < size.max(1) {
+        let (x, y) = (rng.gen_range(0..final_w), rng.gen_range(0..final_h));
+        let mirrors_x: Vec<bool> = (0..fold_count).map(|_| rng.gen_bool(0.5)).collect();
+        let mirrors_y: Vec<bool> = (0..fold_count).map(|_| rng.gen_bool(0.5)).collect();
+        dots.insert((
+            place(x, &widths, &mirrors_x),
+            place(y, &heights, &mirrors_y),
+        ));
+    }
+
+    let mut lines: Vec<String> = dots
+        .into_iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect();
+    lines.sort();
+    lines.push(String::new());
+
+    for i in (0..fold_count).rev() {
+        lines.push(format!("fold along x={}", widths[i]));
+        lines.push(format!("fold along y={}", heights[i]));
+    }
+
+    lines
+}
+
+/// The dots on the part of the sheet that is left after folding
+struct Sheet {
+    dots: SparseGrid<bool>,
+    width: isize,
+    height: isize,
+}
+
+impl Sheet {
+    fn new(points: &Vec<Point>) -> Self {
+        let mut dots = SparseGrid::new(false);
+        for p in points {
+            dots.set((p.x as isize, p.y as isize), true);
+        }
+
+        let [xs, ys] = dots.bounds().map_or([Interval::new(0, 0); 2], |e| e.0);
+        Self {
+            width: xs.end + 1,
+            height: ys.end + 1,
+            dots,
+        }
+    }
+
+    /// Mirrors the dots past the fold line onto the other half, dots on the
+    /// line or mirrored past the edge of the sheet are lost
+    fn fold(&mut self, fold: Fold) {
+        let mirror = |v: isize, line: isize| if v > line { 2 * line - v } else { v };
+        match fold {
+            Fold::AlongX(line) => self.width = line as isize,
+            Fold::AlongY(line) => self.height = line as isize,
+        }
+
+        let mut dots = SparseGrid::new(false);
+        for ((x, y), _) in self.dots.iter() {
+            let (x, y) = match fold {
+                Fold::AlongX(line) => (mirror(x, line as isize), y),
+                Fold::AlongY(line) => (x, mirror(y, line as isize)),
+            };
+            if (0..self.width).contains(&x) && (0..self.height).contains(&y) {
+                dots.set((x, y), true);
+            }
+        }
+        self.dots = dots;
+    }
+}
+
+mod tests {
+    #[test]
+    fn show_empty_sheet() {
+        use super::*;
+
+        let show = |input: &str| {
+            let mut aoc = AOC13::default();
+            aoc.parse(&input.lines().map(String::from).collect())
+                .unwrap();
+            aoc.show()
+        };
+
+        assert_eq!(
+            Some(String::from("#.\n.#")),
+            show("0,0\n3,1\n\nfold along x=2")
+        );
+        assert_eq!(None, show("1,1\n\nfold along x=0"));
+        assert_eq!(None, show("\nfold along y=3"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::diagnostic::{line, lines, Line, ParseError};
+use crate::generate::GenRng;
+use crate::runner::{parse_setting, Runner};
+use rand::seq::SliceRandom;
+
+/// Elements are the letters `A` to `Z`
+const ELEMENTS: usize = 26;
+
+type Element = u8;
+/// How often each pair of elements shows up, indexed by `pair`
+type PairCounter = [usize; ELEMENTS * ELEMENTS];
+
+#[inline]
+fn pair(a: Element, b: Element) -> usize {
+    (a - b'A') as usize * ELEMENTS + (b - b'A') as usize
+}
+
+fn elements<'a>(line: &Line, part: &'a str) -> Result<&'a [Element], ParseError> {
+    if part.bytes().all(|e| e.is_ascii_uppercase()) {
+        Ok(part.as_bytes())
+    } else {
+        Err(line
+            .error(part, "expected elements")
+            .with_hint("elements are the letters `A` to `Z`"))
+    }
+}
+
+#[derive(Default)]
+pub struct AOC14 {
+    template: Vec<Element>,
+    /// The element inserted between each pair, indexed by `pair`
+    pair_lookup: Vec<Option<Element>>,
+    /// overrides the number of insertion steps of both parts
+    steps: Option<usize>,
+}
+
+impl Runner for AOC14 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let l = line(input, 0)?;
+        let template = elements(&l, l.text)?.to_vec();
+
+        let mut pairs = vec![None; ELEMENTS * ELEMENTS];
+        for e in lines(input).skip(2) {
+            let (l, r) = e.split_once(e.text, " -> ")?;
+            if l.len() != 2 {
+                return Err(e.error(l, "expected a pair of two elements"));
+            }
+            if r.len() != 1 {
+                return Err(e.error(r, "expected a single element"));
+            }
+            let l = elements(&e, l)?;
+            pairs[pair(l[0], l[1])] = Some(elements(&e, r)?[0]);
+        }
+
+        self.pair_lookup = pairs;
+        self.template = template;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        run(&self, self.steps.unwrap_or(10))
+    }
+
+    fn run_p2(&self) -> usize {
+        run(&self, self.steps.unwrap_or(40))
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        let mut polymer = self.template.clone();
+        for _ in 0..10 {
+            let mut next = vec![polymer[0]];
+            for w in polymer.windows(2) {
+                if let Some(c) = self.pair_lookup[pair(w[0], w[1])] {
+                    next.push(c);
+                }
+                next.push(w[1]);
+            }
+            polymer = next;
+        }
+
+        let mut counts = HashMap::new();
+        for e in polymer {
+            *counts.entry(e).or_insert(0usize) += 1;
+        }
+        Some(counts.values().max().unwrap() - counts.values().min().unwrap())
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "steps" => self.steps = Some(parse_setting(key, value)?),
+            _ => return Err(format!("Unknown setting {}", key)),
+        }
+        Ok(())
+    }
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let mut alphabet: Vec<u8> = (b'A'..=b'Z').collect();
+    alphabet.shuffle(rng);
+    alphabet.truncate(10);
+
+    let mut template: Vec<u8> = (0..size.max(2))
+        .map(|_| *alphabet.choose(rng).unwrap())
+        .collect();
+    // the last element has to show up as the start of a pair as well
+    template[0] = *template.last().unwrap();
+
+    let mut lines = vec![String::from_utf8(template).unwrap(), String::new()];
+    for &a in &alphabet {
+        for &b in &alphabet {
+            let c = *alphabet.choose(rng).unwrap();
+            lines.push(format!("{}{} -> {}", a as char, b as char, c as char));
+        }
+    }
+
+    lines
+}
+
+fn run(aoc: &AOC14, iter: usize) -> usize {
+    let mut pairs: PairCounter = [0; ELEMENTS * ELEMENTS];
+    for w in aoc.template.windows(2) {
+        pairs[pair(w[0], w[1])] += 1;
+    }
+
+    for i in 0..iter {
+        pairs = step(&pairs, &aoc.pair_lookup);
+        tracing::trace!(step = i, pairs = pairs.iter().filter(|&&n| n > 0).count());
+    }
+
+    // every element starts a pair, except for the last one of the polymer
+    let mut counts = [0usize; ELEMENTS];
+    for (i, n) in pairs.iter().enumerate() {
+        counts[i / ELEMENTS] += n;
+    }
+    counts[(aoc.template.last().unwrap() - b'A') as usize] += 1;
+
+    let (min, max) = counts
+        .iter()
+        .filter(|&&n| n > 0)
+        .fold((usize::MAX, 0), |(min, max), &v| (min.min(v), max.max(v)));
+
+    max - min
+}
+
+fn step(pairs: &PairCounter, lookup: &[Option<Element>]) -> PairCounter {
+    let mut next = [0; ELEMENTS * ELEMENTS];
+
+    for (i, &n) in pairs.iter().enumerate() {
+        if let Some(c) = lookup[i] {
+            let (a, b, c) = (i / ELEMENTS, i % ELEMENTS, (c - b'A') as usize);
+            next[a * ELEMENTS + c] += n;
+            next[c * ELEMENTS + b] += n;
+        }
+    }
+
+    next
+}
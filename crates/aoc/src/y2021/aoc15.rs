@@ -4,7 +4,10 @@ use std::{
     ops::{Add, Sub},
 };
 
+use crate::diagnostic::{digit_grid, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::Rng;
 
 const DIRS: [Point; 4] = [Point(1, 0), Point(0, 1), Point(-1, 0), Point(0, -1)];
 type FindPathResult = (Vec<Point>, usize);
@@ -140,18 +143,16 @@ pub struct AOC15 {
 }
 
 impl Runner for AOC15 {
-    fn parse(&mut self, input: &Vec<String>) {
-        let data: Vec<isize> = input
-            .iter()
-            .map(|e| e.chars().map(|c| c as isize - 48).collect::<Vec<isize>>())
-            .flatten()
-            .collect();
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let grid = digit_grid(input)?;
+        let data: Vec<isize> = grid.iter().flatten().map(|&c| c as isize).collect();
 
         self.map = Map {
-            w: input[0].len(),
-            h: input.len(),
+            w: grid[0].len(),
+            h: grid.len(),
             data: data,
         };
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
@@ -163,7 +164,7 @@ impl Runner for AOC15 {
         let (_path, tot_cost) = find_path(&flowfield, &self.map, &dirs, Point(0, 0), end).unwrap();
 
         // draw_flowfield(&flowfield, &dirs, &path);
-        // plot::_plot_flowfield("./assets/flowfield-p1.png", &flowfield, &dirs, &_path);
+        // plot::_plot_flowfield("flowfield-p1.png", &flowfield, &dirs, &_path);
 
         tot_cost
     }
@@ -180,10 +181,73 @@ impl Runner for AOC15 {
         let (_path, tot_cost) = find_path(&flowfield, &map, &dirs, Point(0, 0), end).unwrap();
 
         // draw_flowfield(&flowfield, &dirs, &path);
-        // plot::_plot_flowfield("./assets/flowfield-p2.png", &flowfield, &dirs, &_path);
+        // plot::_plot_flowfield("flowfield-p2.png", &flowfield, &dirs, &_path);
 
         tot_cost
     }
+
+    fn reference_p1(&self) -> Option<usize> {
+        Some(lowest_risk_reference(&self.map, 1))
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        Some(lowest_risk_reference(&self.map, 5))
+    }
+}
+
+/// Plain Dijkstra from the top left corner, reading risks straight from the
+/// tiled map instead of growing it first.
+fn lowest_risk_reference(map: &Map, tiles: usize) -> usize {
+    let (w, h) = (map.w * tiles, map.h * tiles);
+    let risk = |x: usize, y: usize| {
+        let base = map.data[(y % map.h) * map.w + x % map.w] as usize;
+        (base + x / map.w + y / map.h - 1) % 9 + 1
+    };
+
+    let mut dist = vec![usize::MAX; w * h];
+    let mut open = BinaryHeap::new();
+    dist[0] = 0;
+    open.push(std::cmp::Reverse((0, 0usize, 0usize)));
+
+    while let Some(std::cmp::Reverse((d, x, y))) = open.pop() {
+        crate::cancel::checkpoint();
+        if (x, y) == (w - 1, h - 1) {
+            return d;
+        }
+        if d > dist[y * w + x] {
+            continue;
+        }
+
+        let neighbours = [
+            (x + 1, y),
+            (x, y + 1),
+            (x.wrapping_sub(1), y),
+            (x, y.wrapping_sub(1)),
+        ];
+        for (nx, ny) in neighbours {
+            if nx >= w || ny >= h {
+                continue;
+            }
+            let nd = d + risk(nx, ny);
+            if nd < dist[ny * w + nx] {
+                dist[ny * w + nx] = nd;
+                open.push(std::cmp::Reverse((nd, nx, ny)));
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| (b'0' + rng.gen_range(1..10)) as char)
+                .collect()
+        })
+        .collect()
 }
 
 pub fn generate_risks(map: &Map, end: Point) -> Result<Map, String> {
@@ -209,6 +273,7 @@ pub fn generate_risks(map: &Map, end: Point) -> Result<Map, String> {
     open.push(end);
 
     while let Some(curr) = open.pop() {
+        crate::cancel::checkpoint();
         for n in map.neighbours_pos(curr.point) {
             let n_risk = curr.tot_risk + map.get_risk(&n.into());
 
@@ -330,8 +395,9 @@ mod plot {
     use plotters::prelude::*;
     use std::fs::File;
     use std::io::BufReader;
+    use std::path::Path;
 
-    fn _load_image<'a>(path: &str) -> Vec<Vec<RGBColor>> {
+    fn _load_image<'a>(path: &Path) -> Vec<Vec<RGBColor>> {
         let image = image::load(
             BufReader::new(
                 File::open(path).map_err(|e| {
@@ -363,23 +429,26 @@ mod plot {
         }
     }
 
+    /// Draws the flowfield to `output` in the assets directory
     pub fn _plot_flowfield(output: &str, flowfield: &Map, dirs: &Vec<Point>, path: &Vec<Point>) {
+        let assets = crate::input::assets_dir();
         let arrows: Vec<Vec<Vec<RGBColor>>> = [
-            "./assets/arrow_right.png",
-            "./assets/arrow_down.png",
-            "./assets/arrow_left.png",
-            "./assets/arrow_up.png",
-            "./assets/path_right.png",
-            "./assets/path_down.png",
-            "./assets/path_left.png",
-            "./assets/path_up.png",
+            "arrow_right.png",
+            "arrow_down.png",
+            "arrow_left.png",
+            "arrow_up.png",
+            "path_right.png",
+            "path_down.png",
+            "path_left.png",
+            "path_up.png",
         ]
         .into_iter()
-        .map(|e| _load_image(e))
+        .map(|e| _load_image(&assets.join(e)))
         .collect();
 
+        let output = assets.join(output);
         let root_drawing_area =
-            BitMapBackend::new(output, (flowfield.w as u32 * 8, flowfield.h as u32 * 8))
+            BitMapBackend::new(&output, (flowfield.w as u32 * 8, flowfield.h as u32 * 8))
                 .into_drawing_area();
         root_drawing_area.fill(&WHITE).unwrap();
 
@@ -414,3 +483,26 @@ mod plot {
         root_drawing_area.present().expect("failed to create plot");
     }
 }
+
+mod tests {
+    #[test]
+    fn part_2_stops_at_the_budget() {
+        use super::*;
+        use crate::cancel::{with_budget, TimedOut};
+        use std::time::Duration;
+
+        let input = crate::generate::generate(15, 1, Some(10)).unwrap();
+        let mut aoc = AOC15::default();
+        aoc.parse(&input).unwrap();
+
+        // a run that finishes late times out as well, only a checkpoint
+        // keeps it from finishing
+        let mut finished = false;
+        let result = with_budget(Duration::ZERO, || {
+            aoc.run_p2();
+            finished = true;
+        });
+        assert_eq!(Err(TimedOut(Duration::ZERO)), result);
+        assert!(!finished);
+    }
+}
@@ -1,30 +1,24 @@
-use std::{collections::HashMap, iter::Map, slice::Iter};
+use std::{iter::Map, slice::Iter};
 
+use crate::diagnostic::{line, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
-use lazy_static::lazy_static;
-
-lazy_static! {
-    static ref HEX_TO_BIN: HashMap<u8, [u8; 4]> = {
-        [
-            ('0' as u8, [0, 0, 0, 0]),
-            ('1' as u8, [0, 0, 0, 1]),
-            ('2' as u8, [0, 0, 1, 0]),
-            ('3' as u8, [0, 0, 1, 1]),
-            ('4' as u8, [0, 1, 0, 0]),
-            ('5' as u8, [0, 1, 0, 1]),
-            ('6' as u8, [0, 1, 1, 0]),
-            ('7' as u8, [0, 1, 1, 1]),
-            ('8' as u8, [1, 0, 0, 0]),
-            ('9' as u8, [1, 0, 0, 1]),
-            ('A' as u8, [1, 0, 1, 0]),
-            ('B' as u8, [1, 0, 1, 1]),
-            ('C' as u8, [1, 1, 0, 0]),
-            ('D' as u8, [1, 1, 0, 1]),
-            ('E' as u8, [1, 1, 1, 0]),
-            ('F' as u8, [1, 1, 1, 1]),
-        ]
-        .into()
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The four bits of an uppercase hexadecimal digit
+fn hex_to_bin(digit: u8) -> Option<[u8; 4]> {
+    let value = match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => return None,
     };
+    Some([
+        (value >> 3) & 1,
+        (value >> 2) & 1,
+        (value >> 1) & 1,
+        value & 1,
+    ])
 }
 
 type Number = u64;
@@ -167,14 +161,25 @@ pub struct AOC16 {
 }
 
 impl Runner for AOC16 {
-    fn parse(&mut self, input: &Vec<String>) {
-        let as_binary = input[0]
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let l = line(input, 0)?;
+        let as_binary = l
+            .text
             .bytes()
-            .map(|e| HEX_TO_BIN[&e])
+            .enumerate()
+            .map(|(i, e)| {
+                hex_to_bin(e).ok_or_else(|| {
+                    l.error_at(i, 1, "expected a hexadecimal digit")
+                        .with_hint("the transmission only uses `0` to `9` and `A` to `F`")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .flatten()
             .collect::<Vec<u8>>();
 
         self.bits = as_binary;
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
@@ -190,6 +195,97 @@ impl Runner for AOC16 {
     }
 }
 
+fn push_bits(bits: &mut Vec<u8>, num: Number, len: usize) {
+    for i in (0..len).rev() {
+        bits.push(((num >> i) & 1) as u8);
+    }
+}
+
+fn push_literal(bits: &mut Vec<u8>, num: Number) {
+    let grps = (1..=16).find(|&i| i == 16 || num >> (i * 4) == 0).unwrap();
+    for i in (0..grps).rev() {
+        bits.push(if i > 0 { 1 } else { 0 });
+        push_bits(bits, num >> (i * 4), 4);
+    }
+}
+
+/// Writes a random packet of at most `depth` nested operators to `bits` and
+/// returns the value it evaluates to.
+fn generate_packet(rng: &mut GenRng, depth: usize, bits: &mut Vec<u8>) -> Number {
+    push_bits(bits, rng.gen_range(0..8), 3);
+
+    if depth == 0 {
+        let val = rng.gen_range(0..1 << 12);
+        push_bits(bits, 4, 3);
+        push_literal(bits, val);
+        return val;
+    }
+
+    let mut type_id = *[0, 1, 2, 3, 5, 6, 7].choose(rng).unwrap();
+    let cnt = if type_id >= 5 {
+        2
+    } else {
+        rng.gen_range(1..=4)
+    };
+
+    let mut sub_bits = Vec::new();
+    let vals: Vec<Number> = (0..cnt)
+        .map(|_| {
+            let depth = if rng.gen_bool(0.3) { 0 } else { depth - 1 };
+            generate_packet(rng, depth, &mut sub_bits)
+        })
+        .collect();
+
+    let checked = match type_id {
+        0 => vals
+            .iter()
+            .try_fold(0 as Number, |acc, &e| acc.checked_add(e)),
+        1 => vals
+            .iter()
+            .try_fold(1 as Number, |acc, &e| acc.checked_mul(e)),
+        _ => Some(0),
+    };
+    // sums and products that would overflow are turned into a minimum instead
+    if checked.is_none() {
+        type_id = 2;
+    }
+
+    let val = match type_id {
+        0 | 1 => checked.unwrap(),
+        2 => *vals.iter().min().unwrap(),
+        3 => *vals.iter().max().unwrap(),
+        5 => (vals[0] > vals[1]) as Number,
+        6 => (vals[0] < vals[1]) as Number,
+        7 => (vals[0] == vals[1]) as Number,
+        _ => unreachable!(),
+    };
+
+    push_bits(bits, type_id, 3);
+    if sub_bits.len() < 1 << 15 && rng.gen_bool(0.5) {
+        bits.push(0);
+        push_bits(bits, sub_bits.len() as Number, 15);
+    } else {
+        bits.push(1);
+        push_bits(bits, cnt as Number, 11);
+    }
+    bits.extend(sub_bits);
+
+    val
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let mut bits = Vec::new();
+    generate_packet(rng, size, &mut bits);
+    while bits.len() % 4 != 0 {
+        bits.push(0);
+    }
+
+    vec![bits
+        .chunks(4)
+        .map(|e| format!("{:X}", Parser::bin_to_num(e, 0)))
+        .collect()]
+}
+
 fn sum_packet_versions(packet: &Packet) -> Number {
     let mut sum = packet.version;
 
@@ -252,7 +348,7 @@ mod tests {
     fn _create_runner(input: &str) -> AOC16 {
         let input = vec![input.to_string()];
         let mut aoc = AOC16::default();
-        aoc.parse(&input);
+        aoc.parse(&input).unwrap();
         aoc
     }
 
@@ -0,0 +1,181 @@
+use std::ops::{Add, Sub};
+
+use crate::diagnostic::{line, Line, ParseError};
+use crate::generate::GenRng;
+use crate::intervals::{Cuboid, Interval};
+use crate::runner::Runner;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC17 {
+    x_range: Interval,
+    y_range: Interval,
+}
+
+fn parse_range(line: &Line, input: &str, axis: &str) -> Result<Interval, ParseError> {
+    let range = line.strip_prefix(input, axis)?;
+    let (x0, x1) = line.split_once(range, "..")?;
+    let (x0, x1) = (line.number::<isize>(x0)?, line.number::<isize>(x1)?);
+
+    if x0 > x1 {
+        return Err(line
+            .error(range, format!("range {}..{} is empty", x0, x1))
+            .with_hint("ranges go from the lower to the higher coordinate"));
+    }
+    Ok(Interval::new(x0, x1))
+}
+
+impl Runner for AOC17 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let l = line(input, 0)?;
+        let r = l.strip_prefix(l.text, "target area: ")?;
+        let (x, y) = l.split_once(r, ", ")?;
+
+        self.x_range = parse_range(&l, x, "x=")?;
+        self.y_range = parse_range(&l, y, "y=")?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        /*
+        the maximum height we can go before the velocity when reaching y = 0
+        is equal to the lower y bounds of the target area
+
+        since "gravity" is 1 we can just do the triangle formula sum on the lower
+        y bounds of the target area minus one to find the maximum height.
+        using that formula works since we just subtract one from the veloicty each step
+
+        the x velocity doesnt matter since we can just assume it's the lowest
+        amount for the x velocity to stop at the lower x bound of the target area
+        */
+        sum(-self.y_range.start - 1) as usize
+    }
+
+    fn run_p2(&self) -> usize {
+        let target = Cuboid([self.x_range, self.y_range]);
+        let max_y = -self.y_range.start;
+
+        let mut hits = 0;
+        for x in 0..=self.x_range.end {
+            if sum(x) < self.x_range.start {
+                continue;
+            }
+
+            for y in self.y_range.start..max_y {
+                if sim(Point(x, y), &target) {
+                    hits += 1;
+                }
+            }
+        }
+
+        hits as usize
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        Some(launch_reference(self.x_range, self.y_range).0)
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        Some(launch_reference(self.x_range, self.y_range).1)
+    }
+}
+
+/// Tries every launch velocity that could possibly reach the target and
+/// returns the highest point reached by a hit and the number of hits.
+fn launch_reference(x_range: Interval, y_range: Interval) -> (usize, usize) {
+    let mut best = 0;
+    let mut hits = 0;
+
+    for vx in 0..=x_range.end {
+        for vy in y_range.start..=-y_range.start {
+            let (mut x, mut y) = (0, 0);
+            let (mut dx, mut dy) = (vx, vy);
+            let mut top = 0;
+
+            while x <= x_range.end && (y >= y_range.start || dy > 0) {
+                x += dx;
+                y += dy;
+                dx -= dx.signum();
+                dy -= 1;
+                top = top.max(y);
+
+                if x_range.contains(x) && y_range.contains(y) {
+                    best = best.max(top);
+                    hits += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    (best as usize, hits)
+}
+
+/// The target is always to the right and below the launcher, and its x range
+/// contains a triangular number so some x velocity stops inside it.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(15) as isize;
+
+    let x1 = rng.gen_range(size / 2..=size);
+    let stop = (1..).map(sum).find(|&e| e >= x1).unwrap();
+    let x2 = stop.max(x1 + rng.gen_range(5..=size / 3));
+
+    let y1 = -rng.gen_range(size / 2..=size);
+    let y2 = (y1 + rng.gen_range(5..=size / 3)).min(-1);
+
+    vec![format!("target area: x={}..{}, y={}..{}", x1, x2, y1, y2)]
+}
+
+#[inline]
+fn sum(num: isize) -> isize {
+    num * (num + 1) / 2
+}
+
+#[inline]
+fn sim(mut vel: Point, target: &Cuboid<2>) -> bool {
+    let mut pos = Point(0, 0);
+    loop {
+        pos = pos + vel;
+        vel.0 = vel.0 + -dir(vel.0);
+        vel.1 = vel.1 - 1;
+
+        if target.contains([pos.0, pos.1]) {
+            return true;
+        }
+
+        // the probe only moves right and, once past the top, down
+        if pos.0 > target.0[0].end || pos.1 < target.0[1].start {
+            break;
+        }
+    }
+
+    false
+}
+
+#[inline]
+fn dir(num: isize) -> isize {
+    match num {
+        0 => 0,
+        _ if num < 0 => -1,
+        _ => 1,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Point(pub isize, pub isize);
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, r: Point) -> <Self as std::ops::Add<Point>>::Output {
+        Point(self.0 + r.0, self.1 + r.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
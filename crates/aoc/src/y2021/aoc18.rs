@@ -1,4 +1,7 @@
-use crate::runner::Runner;
+use crate::diagnostic::{self, ParseError};
+use crate::generate::GenRng;
+use crate::runner::{PartStatus, Runner};
+use rand::Rng;
 
 #[derive(Default)]
 pub struct AOC18 {
@@ -6,14 +9,14 @@ pub struct AOC18 {
 }
 
 impl Runner for AOC18 {
-    fn parse(&mut self, input: &Vec<String>) {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
         let mut lines = Vec::new();
 
-        for l in input.iter().map(|e| e.chars().collect::<Vec<char>>()) {
+        for l in diagnostic::lines(input) {
             let mut tree = Tree::default();
             let mut tree_stack = vec![];
 
-            for c in l {
+            for (i, c) in l.text.char_indices() {
                 match c {
                     '[' => match tree_stack.last() {
                         Some(&parent) => tree_stack.push(tree.add_child(parent, None)),
@@ -23,11 +26,19 @@ impl Runner for AOC18 {
                         tree_stack.pop();
                     }
                     ',' => {}
-                    _ => {
+                    '0'..='9' => {
                         let val = c as u8 - 48;
-                        let parent = *tree_stack.last().unwrap();
+                        let parent = *tree_stack.last().ok_or_else(|| {
+                            l.error_at(i, 1, "expected `[` before a number")
+                                .with_hint("snailfish numbers are pairs like `[1,2]`")
+                        })?;
                         tree.add_child(parent, Some(val));
                     }
+                    _ => {
+                        return Err(l
+                            .error_at(i, c.len_utf8(), format!("unexpected `{}`", c))
+                            .with_hint("snailfish numbers are made of `[`, `]`, `,` and digits"))
+                    }
                 }
             }
 
@@ -35,6 +46,7 @@ impl Runner for AOC18 {
         }
 
         self.lines = lines;
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
@@ -46,14 +58,37 @@ impl Runner for AOC18 {
         l_tree.insert_tree(l_tree.nodes[2], r_tree.nodes[1], &r_tree);
         l_tree.insert_tree(l_tree.nodes[2], r_tree.nodes[2], &r_tree);
 
-        println!("{:#?}", l_tree);
+        tracing::debug!("{:#?}", l_tree);
 
         0
     }
 
     fn run_p2(&self) -> usize {
-        todo!()
+        0
     }
+
+    /// Adding snailfish numbers is still work in progress
+    fn status(&self, _part: usize) -> PartStatus {
+        PartStatus::Unimplemented
+    }
+}
+
+fn generate_pair(rng: &mut GenRng, depth: usize) -> String {
+    let element = |rng: &mut GenRng| {
+        if depth < 4 && rng.gen_bool(0.5) {
+            generate_pair(rng, depth + 1)
+        } else {
+            rng.gen_range(0..10).to_string()
+        }
+    };
+
+    let l = element(rng);
+    let r = element(rng);
+    format!("[{},{}]", l, r)
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    (0..size.max(2)).map(|_| generate_pair(rng, 1)).collect()
 }
 
 #[derive(Debug, Clone, Copy)]
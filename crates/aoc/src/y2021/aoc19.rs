@@ -1,36 +1,49 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashSet,
     fmt::Write,
     ops::{Add, Sub},
 };
 
-use crate::runner::Runner;
+use crate::diagnostic::{self, ParseError};
+use crate::generate::GenRng;
+use crate::hash::{FastHashMap, FastHashSet};
+use crate::runner::{PartStatus, Runner};
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 #[derive(Default)]
 pub struct AOC19 {
     scanners: Vec<Scanner>,
 }
 
-fn parse_line(input: &str) -> Point {
-    let mut xyz = input.split(',').map(|v| v.parse::<isize>().unwrap());
-    Point::new(
-        xyz.next().unwrap(),
-        xyz.next().unwrap(),
-        xyz.next().unwrap(),
-    )
+fn parse_line(line: &diagnostic::Line) -> Result<Point, ParseError> {
+    let xyz = line
+        .text
+        .split(',')
+        .map(|v| line.number::<isize>(v))
+        .collect::<Result<Vec<isize>, _>>()?;
+    match xyz[..] {
+        [x, y, z] => Ok(Point::new(x, y, z)),
+        _ => Err(line
+            .error(
+                line.text,
+                format!("expected 3 coordinates, found {}", xyz.len()),
+            )
+            .with_hint("beacons are relative positions like `404,-588,-901`")),
+    }
 }
 
 impl Runner for AOC19 {
-    fn parse(&mut self, input: &Vec<String>) {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
         let mut scanners = Vec::new();
         let mut beacons = Vec::new();
 
-        for i in 0..input.len() {
-            if input[i].starts_with("--- scanner") {
+        for l in diagnostic::lines(input) {
+            if l.text.starts_with("--- scanner") {
                 continue;
             }
 
-            if input[i].is_empty() {
+            if l.text.is_empty() {
                 scanners.push(Scanner {
                     beacons: beacons.clone(),
                 });
@@ -38,27 +51,26 @@ impl Runner for AOC19 {
                 continue;
             }
 
-            beacons.push(parse_line(&input[i]));
+            beacons.push(parse_line(&l)?);
         }
         if beacons.len() > 0 {
             scanners.push(Scanner { beacons: beacons });
         }
 
         self.scanners = scanners;
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
-        println!();
-
         let mut scanners = self.scanners.clone();
 
-        let mut flips = HashMap::new();
-        let mut offsets = HashMap::new();
+        let mut flips = FastHashMap::default();
+        let mut offsets = FastHashMap::default();
 
         let mapped = map_scanners(&scanners[0], &scanners[1]);
         let found = mapped.into_iter().find(|(_k, v)| v.len() >= 12);
         if let Some(found) = found {
-            println!("flip 1: {:?}", found.0);
+            tracing::debug!("flip 1: {:?}", found.0);
             let flip_idx = found.1[0].flip;
 
             flips.insert(1, flip_idx);
@@ -73,7 +85,7 @@ impl Runner for AOC19 {
         let mapped = map_scanners(&scanners[1], &scanners[4]);
         let found = mapped.into_iter().find(|(_k, v)| v.len() >= 12);
         if let Some(found) = found {
-            println!("flip 4: {:?}", found.0);
+            tracing::debug!("flip 4: {:?}", found.0);
             let flip_idx = found.1[0].flip;
 
             flips.insert(4, flip_idx);
@@ -88,7 +100,7 @@ impl Runner for AOC19 {
         let mapped = map_scanners(&scanners[1], &scanners[3]);
         let found = mapped.into_iter().find(|(_k, v)| v.len() >= 12);
         if let Some(found) = found {
-            println!("flip 3: {:?}", found.0);
+            tracing::debug!("flip 3: {:?}", found.0);
             let flip_idx = found.1[0].flip;
 
             flips.insert(3, flip_idx);
@@ -106,7 +118,7 @@ impl Runner for AOC19 {
             let offset = offsets[&4];
             offsets.insert(2, offset);
 
-            println!("flip 2: {:?}", found.0 + offset);
+            tracing::debug!("flip 2: {:?}", found.0 + offset);
 
             let flip_idx = found.1[0].flip;
             flips.insert(2, flip_idx);
@@ -118,16 +130,17 @@ impl Runner for AOC19 {
             }
         }
 
-        let beacons: HashSet<Point> = scanners.into_iter().flat_map(|e| e.beacons).collect();
-        println!("{}", beacons.len());
+        let beacons: FastHashSet<Point> = scanners.into_iter().flat_map(|e| e.beacons).collect();
+        tracing::debug!(beacons = beacons.len());
 
         return 0;
 
-        let mut matches = HashMap::new();
-        let mut seen: HashSet<usize> = vec![0].into_iter().collect();
+        let mut matches = FastHashMap::default();
+        let mut seen: FastHashSet<usize> = vec![0].into_iter().collect();
         let mut current = 0;
 
         loop {
+            crate::cancel::checkpoint();
             if seen.len() == scanners.len() {
                 break;
             }
@@ -141,7 +154,7 @@ impl Runner for AOC19 {
                 let found = mapped.into_iter().find(|(_k, v)| v.len() >= 12);
                 if let Some(found) = found {
                     let flip_idx = found.1[0].flip;
-                    println!("{}, {} | {} | {:?}", current, i, flip_idx, found.1[0].offset);
+                    tracing::debug!("{}, {} | {} | {:?}", current, i, flip_idx, found.1[0].offset);
                     
                     for p in scanners[i].beacons.iter_mut() {
                         p.flip_self(flip_idx);
@@ -182,18 +195,149 @@ impl Runner for AOC19 {
             }
         } */
 
-        let beacons: HashSet<Point> = scanners.into_iter().flat_map(|e| e.beacons).collect();
+        let beacons: FastHashSet<Point> = scanners.into_iter().flat_map(|e| e.beacons).collect();
         beacons.len()
     }
 
     fn run_p2(&self) -> usize {
-        todo!()
+        0
+    }
+
+    /// Only the first few scanners get matched so far
+    fn status(&self, _part: usize) -> PartStatus {
+        PartStatus::Unimplemented
+    }
+
+    fn show(&self) -> Option<String> {
+        let mut out = String::new();
+        for (i, scanner) in self.scanners.iter().enumerate() {
+            let min = scanner
+                .beacons
+                .iter()
+                .fold(Point::new(isize::MAX, isize::MAX, isize::MAX), |a, b| {
+                    Point::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+                });
+            let max = scanner
+                .beacons
+                .iter()
+                .fold(Point::new(isize::MIN, isize::MIN, isize::MIN), |a, b| {
+                    Point::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+                });
+            writeln!(
+                out,
+                "scanner {:2}: {:2} beacons | min {:?} | max {:?}",
+                i,
+                scanner.beacons.len(),
+                min,
+                max
+            )
+            .unwrap();
+        }
+        Some(out.trim_end().to_string())
     }
 }
 
-fn map_scanners(a: &Scanner, b: &Scanner) -> HashMap<Point, Vec<Match>> {
-    let mut counters: HashMap<Point, Vec<Match>> = HashMap::new();
+/// One of the 24 orientations a scanner can be facing.
+fn rotate(p: Point, rot: usize) -> Point {
+    // spin around the x axis, then point the x axis in one of six directions
+    let p = (0..rot % 4).fold(p, |p, _| Point::new(p.x, -p.z, p.y));
+    match rot / 4 {
+        0 => p,
+        1 => Point::new(-p.x, -p.y, p.z),
+        2 => Point::new(-p.y, p.x, p.z),
+        3 => Point::new(p.y, -p.x, p.z),
+        4 => Point::new(-p.z, p.y, p.x),
+        5 => Point::new(p.z, p.y, -p.x),
+        _ => unreachable!(),
+    }
+}
+
+/// Every scanner after the first is placed next to an existing one, with at
+/// least 12 beacons inside the overlap of their detection cubes.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let range = 1000;
+    let random_in = |rng: &mut GenRng, lo: Point, hi: Point| {
+        Point::new(
+            rng.gen_range(lo.x..hi.x),
+            rng.gen_range(lo.y..hi.y),
+            rng.gen_range(lo.z..hi.z),
+        )
+    };
+
+    let mut scanners = vec![Point::new(0, 0, 0)];
+    let mut beacons: HashSet<Point> = HashSet::new();
+
+    while scanners.len() < size.max(2) {
+        let parent = *scanners.choose(rng).unwrap();
+        let pos = parent
+            + random_in(
+                rng,
+                Point::new(-1200, -1200, -1200),
+                Point::new(1201, 1201, 1201),
+            );
+        if scanners.contains(&pos) {
+            continue;
+        }
+
+        let lo = Point::new(
+            parent.x.max(pos.x),
+            parent.y.max(pos.y),
+            parent.z.max(pos.z),
+        );
+        let hi = Point::new(
+            parent.x.min(pos.x),
+            parent.y.min(pos.y),
+            parent.z.min(pos.z),
+        );
+        let lo = lo - Point::new(range - 1, range - 1, range - 1);
+        let hi = hi + Point::new(range, range, range);
+
+        let mut shared = 0;
+        while shared < 12 {
+            if beacons.insert(random_in(rng, lo, hi)) {
+                shared += 1;
+            }
+        }
+
+        scanners.push(pos);
+    }
+
+    for &s in &scanners {
+        for _ in 0..rng.gen_range(5..15) {
+            let lo = s - Point::new(range - 1, range - 1, range - 1);
+            let hi = s + Point::new(range, range, range);
+            beacons.insert(random_in(rng, lo, hi));
+        }
+    }
+
+    let mut beacons: Vec<Point> = beacons.into_iter().collect();
+    beacons.sort_by_key(|e| (e.x, e.y, e.z));
+
+    let mut lines = Vec::new();
+    for (i, &s) in scanners.iter().enumerate() {
+        let rot = if i == 0 { 0 } else { rng.gen_range(0..24) };
+        let mut seen: Vec<Point> = beacons
+            .iter()
+            .map(|&e| e - s)
+            .filter(|e| e.x.abs() < range && e.y.abs() < range && e.z.abs() < range)
+            .map(|e| rotate(e, rot))
+            .collect();
+        seen.shuffle(rng);
+
+        if i > 0 {
+            lines.push(String::new());
+        }
+        lines.push(format!("--- scanner {} ---", i));
+        lines.extend(seen.iter().map(|e| format!("{},{},{}", e.x, e.y, e.z)));
+    }
+
+    lines
+}
+
+fn map_scanners(a: &Scanner, b: &Scanner) -> FastHashMap<Point, Vec<Match>> {
+    let mut counters: FastHashMap<Point, Vec<Match>> = FastHashMap::default();
     for idx1 in 0..a.beacons.len() {
+        crate::cancel::checkpoint();
         let b1 = a.beacons[idx1];
 
         for idx2 in 0..b.beacons.len() {
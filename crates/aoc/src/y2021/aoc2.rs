@@ -0,0 +1,91 @@
+use crate::diagnostic::{lines, ParseError};
+use crate::generate::GenRng;
+use crate::runner::Runner;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC2 {
+    parsed: Vec<Direction>,
+}
+
+impl Runner for AOC2 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        self.parsed = lines(input)
+            .map(|l| {
+                let (dir, val) = l.split_once(l.text, " ")?;
+                let val: isize = l.number::<isize>(val)?;
+                match dir {
+                    "forward" => Ok(Direction::Forward(val)),
+                    "up" => Ok(Direction::Up(val)),
+                    "down" => Ok(Direction::Down(val)),
+                    _ => Err(l
+                        .error(dir, format!("unknown direction `{}`", dir))
+                        .with_hint("the submarine only goes forward, up or down")),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        let mut depth: isize = 0;
+        let mut pos: isize = 0;
+
+        for dir in &self.parsed {
+            match dir {
+                Direction::Forward(val) => pos += val,
+                Direction::Down(val) => depth += val,
+                Direction::Up(val) => depth -= val,
+            }
+        }
+
+        (depth * pos) as usize
+    }
+    fn run_p2(&self) -> usize {
+        let mut depth = 0isize;
+        let mut pos = 0isize;
+        let mut aim = 0isize;
+        for dir in &self.parsed {
+            match dir {
+                Direction::Forward(val) => {
+                    pos += val;
+                    depth += aim * val;
+                }
+                Direction::Down(val) => {
+                    aim += val;
+                }
+                Direction::Up(val) => {
+                    aim -= val;
+                }
+            }
+        }
+
+        (depth * pos) as usize
+    }
+}
+
+enum Direction {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let mut depth = 0;
+    (0..size)
+        .map(|_| {
+            let val = rng.gen_range(1..10);
+            match rng.gen_range(0..3) {
+                0 => format!("forward {}", val),
+                1 if depth >= val => {
+                    depth -= val;
+                    format!("up {}", val)
+                }
+                _ => {
+                    depth += val;
+                    format!("down {}", val)
+                }
+            }
+        })
+        .collect()
+}
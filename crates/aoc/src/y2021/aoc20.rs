@@ -0,0 +1,218 @@
+use std::u8;
+use crate::diagnostic::{self, line, lines, ParseError};
+use crate::generate::GenRng;
+use crate::grid::{positions, Grid, InfiniteGrid, Pos};
+use crate::intervals::{Cuboid, Interval};
+use crate::runner::{parse_setting, Runner};
+use crate::sim::Simulation;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC20 {
+    lookup: Vec<u8>,
+    image: Vec<Vec<u8>>,
+    /// overrides the number of enhancements of both parts
+    steps: Option<usize>,
+}
+
+impl Runner for AOC20 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        self.lookup = pixels(&line(input, 0)?)?;
+
+        line(input, 2)?;
+        self.image = lines(input)
+            .skip(2)
+            .map(|l| pixels(&l))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        run(&self.image, &self.lookup, self.steps.unwrap_or(2))
+    }
+
+    fn run_p2(&self) -> usize {
+        run(&self.image, &self.lookup, self.steps.unwrap_or(50))
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        Some(enhance_reference(
+            &self.image,
+            &self.lookup,
+            self.steps.unwrap_or(2),
+        ))
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        Some(enhance_reference(
+            &self.image,
+            &self.lookup,
+            self.steps.unwrap_or(50),
+        ))
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "steps" => self.steps = Some(parse_setting(key, value)?),
+            _ => return Err(format!("Unknown setting {}", key)),
+        }
+        Ok(())
+    }
+
+    fn show(&self) -> Option<String> {
+        let rows = self
+            .image
+            .iter()
+            .map(|l| l.iter().map(bit_to_pixel).collect::<String>());
+        Some(rows.collect::<Vec<String>>().join("\n"))
+    }
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let pixel = |rng: &mut GenRng| if rng.gen_bool(0.5) { '#' } else { '.' };
+
+    let mut lookup: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    // if the infinite dark background lights up it has to go dark again
+    if lookup[0] == '#' {
+        lookup[511] = '.';
+    }
+
+    let mut lines = vec![lookup.into_iter().collect(), String::new()];
+    lines.extend((0..size).map(|_| (0..size).map(|_| pixel(rng)).collect::<String>()));
+    lines
+}
+
+fn run(image: &[Vec<u8>], filter: &[u8], iters: usize) -> usize {
+    let mut enhance = Enhance::new(image, filter);
+    enhance.run(iters);
+    enhance.observe()
+}
+
+/// The image being enhanced, on an infinite plane where every pixel outside
+/// of it is the same
+struct Enhance<'a> {
+    img: InfiniteGrid<u8>,
+    filter: &'a [u8],
+    steps: usize,
+}
+
+impl<'a> Enhance<'a> {
+    fn new(image: &[Vec<u8>], filter: &'a [u8]) -> Self {
+        let mut img = InfiniteGrid::new(0);
+        for (y, row) in image.iter().enumerate() {
+            for (x, &pixel) in row.iter().enumerate() {
+                img.set((x as isize, y as isize), pixel);
+            }
+        }
+
+        Enhance {
+            img,
+            filter,
+            steps: 0,
+        }
+    }
+}
+
+impl Simulation for Enhance<'_> {
+    /// The image grows by a pixel on every side, the pixels outside of it
+    /// all see the same nine pixels and flip together
+    fn step(&mut self) {
+        let outside = match self.img.default_value() {
+            0 => self.filter[0],
+            _ => self.filter[511],
+        };
+        let mut img = InfiniteGrid::new(outside);
+
+        if let Some(Cuboid([xs, ys])) = self.img.bounds() {
+            let grown = Cuboid([
+                Interval::new(xs.start - 1, xs.end + 1),
+                Interval::new(ys.start - 1, ys.end + 1),
+            ]);
+            for pos in positions(grown) {
+                img.set(pos, self.filter[conv(&self.img, pos)]);
+            }
+        }
+
+        self.img = img;
+        tracing::trace!(step = self.steps);
+        self.steps += 1;
+    }
+
+    /// Lit pixels of the part the image has grown to
+    fn observe(&self) -> usize {
+        self.img.count(|&e| e == 1)
+    }
+}
+
+/// Enhances the image in a grid padded wide enough that its edge stays the
+/// background, reading past the edge as the pixel on it.
+fn enhance_reference(image: &[Vec<u8>], filter: &[u8], steps: usize) -> usize {
+    let pad = steps + 1;
+    let (w, h) = (image[0].len() + 2 * pad, image.len() + 2 * pad);
+    let mut img = vec![vec![0u8; w]; h];
+    for (y, row) in image.iter().enumerate() {
+        img[y + pad][pad..pad + row.len()].copy_from_slice(row);
+    }
+
+    for _ in 0..steps {
+        let at = |x: usize, y: usize| img[y.clamp(1, h) - 1][x.clamp(1, w) - 1] as usize;
+        img = (0..h)
+            .map(|y| {
+                (0..w)
+                    .map(|x| {
+                        let mut conv = 0;
+                        for y in y..y + 3 {
+                            for x in x..x + 3 {
+                                conv = (conv << 1) | at(x, y);
+                            }
+                        }
+                        filter[conv]
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    // the image grew by a pixel a step, up to a pixel from the edge
+    img[1..h - 1]
+        .iter()
+        .map(|row| row[1..w - 1].iter().filter(|&&e| e == 1).count())
+        .sum()
+}
+
+/// The nine pixels around `pos` read as a binary number
+fn conv(img: &InfiniteGrid<u8>, (x, y): Pos) -> usize {
+    let mut conv = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            conv = (conv << 1) | *img.get((x + dx, y + dy)) as usize;
+        }
+    }
+    conv
+}
+
+fn pixels(line: &diagnostic::Line) -> Result<Vec<u8>, ParseError> {
+    match line.text.char_indices().find(|(_, c)| *c != '#' && *c != '.') {
+        Some((i, c)) => Err(line
+            .error_at(i, c.len_utf8(), format!("expected `#` or `.`, found `{}`", c))
+            .with_hint("light pixels are `#` and dark pixels are `.`")),
+        None => Ok(line.text.chars().map(pixel_to_bit).collect()),
+    }
+}
+
+fn pixel_to_bit(pixel: char) -> u8 {
+    match pixel {
+        '#' => 1,
+        '.' => 0,
+        _ => unreachable!(),
+    }
+}
+
+fn bit_to_pixel(bit: &u8) -> char {
+    match bit {
+        0 => '-',
+        1 => '█',
+        _ => unreachable!(),
+    }
+}
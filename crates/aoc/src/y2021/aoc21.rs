@@ -1,6 +1,9 @@
-use std::collections::HashMap;
-
+use crate::diagnostic::{line, ParseError};
+use crate::generate::GenRng;
+use crate::hash::FastHashMap;
 use crate::runner::Runner;
+use crate::sim::Simulation;
+use rand::Rng;
 
 #[derive(Default)]
 pub struct AOC21 {
@@ -9,23 +12,30 @@ pub struct AOC21 {
 }
 
 impl Runner for AOC21 {
-    fn parse(&mut self, input: &Vec<String>) {
-        self.p1_start = (input[0].bytes().last().unwrap() - 48) as usize;
-        self.p2_start = (input[1].bytes().last().unwrap() - 48) as usize;
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let start = |index: usize| {
+            let l = line(input, index)?;
+            match l.text.bytes().last() {
+                Some(b @ b'0'..=b'9') => Ok((b - 48) as usize),
+                _ => Err(l
+                    .error_at(l.text.len(), 1, "expected a starting position")
+                    .with_hint("the line should end with a digit, like `position: 4`")),
+            }
+        };
+
+        self.p1_start = start(0)?;
+        self.p2_start = start(1)?;
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
         let mut board = Board::new(self.p1_start, self.p2_start, |roll| (roll % 100) + 1);
-
-        loop {
-            if let Some(loser) = board.do_turn() {
-                return board.players[loser].1 as usize * board.rolls as usize;
-            }
-        }
+        board.run_until(|board| board.loser.is_some());
+        board.observe()
     }
 
     fn run_p2(&self) -> usize {
-        let mut lookup = HashMap::new();
+        let mut lookup = FastHashMap::default();
         let board = QuantumBoard::new(self.p1_start, self.p2_start);
 
         let sum = sim_board(board, 0, &mut lookup);
@@ -33,13 +43,19 @@ impl Runner for AOC21 {
     }
 }
 
+pub fn generate(rng: &mut GenRng, _size: usize) -> Vec<String> {
+    (1..=2)
+        .map(|i| format!("Player {} starting position: {}", i, rng.gen_range(1..=10)))
+        .collect()
+}
+
 const _BELL: [u128; 7] = [1, 3, 6, 7, 6, 3, 1];
 type LookupKey = [u16; 2];
 
 fn sim_board(
     mut board: QuantumBoard,
     roll: u16,
-    lookup: &mut HashMap<LookupKey, [usize; 2]>,
+    lookup: &mut FastHashMap<LookupKey, [usize; 2]>,
 ) -> [usize; 2] {
     if roll > 0 {
         if let Some(turn) = board.do_roll(roll) {
@@ -125,6 +141,8 @@ struct Board {
     players: [(u16, u16); 2],
     turn: u8,
     dice: fn(u16) -> u16,
+    /// set once a player has won
+    loser: Option<usize>,
 }
 
 impl Board {
@@ -134,6 +152,7 @@ impl Board {
             players: [(p1 as u16, 0), (p2 as u16, 0)],
             dice: dice,
             turn: 0,
+            loser: None,
         }
     }
 
@@ -161,3 +180,18 @@ impl Board {
         p.1 >= 1000
     }
 }
+
+impl Simulation for Board {
+    fn step(&mut self) {
+        self.loser = self.do_turn();
+    }
+
+    /// Score of the losing player times the number of rolls, 0 before the
+    /// game is over
+    fn observe(&self) -> usize {
+        match self.loser {
+            Some(loser) => self.players[loser].1 as usize * self.rolls as usize,
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use crate::diagnostic::{lines, Line, ParseError};
+use crate::generate::GenRng;
+use crate::intervals::{Cuboid, CuboidSet, Interval};
+use crate::runner::Runner;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC22 {
+    steps: Vec<Step>,
+}
+
+/// A line of the reboot, turning a cuboid on or off
+#[derive(Debug, Clone)]
+struct Step {
+    on: bool,
+    cuboid: Cuboid<3>,
+}
+
+fn parse_range(line: &Line, input: &str, axis: &str) -> Result<Interval, ParseError> {
+    let range = line.strip_prefix(input.trim(), axis)?;
+    let (x0, x1) = line.split_once(range, "..")?;
+    let (x0, x1) = (line.number::<isize>(x0)?, line.number::<isize>(x1)?);
+
+    if x0 > x1 {
+        return Err(line
+            .error(range, format!("range {}..{} is empty", x0, x1))
+            .with_hint("ranges go from the lower to the higher coordinate"));
+    }
+    Ok(Interval::new(x0, x1))
+}
+
+impl Runner for AOC22 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        self.steps = lines(input)
+            .map(|e| {
+                let (on, xyz) = match e.text.split_once(' ') {
+                    Some(("on", xyz)) => (true, xyz),
+                    Some(("off", xyz)) => (false, xyz),
+                    _ => {
+                        let word = e.text.split(' ').next().unwrap();
+                        return Err(e.error(word, "expected `on` or `off`"));
+                    }
+                };
+
+                let mut xyz = xyz.split_terminator(',');
+                let mut range = |axis| parse_range(&e, xyz.next().unwrap_or(""), axis);
+
+                let x = range("x=")?;
+                let y = range("y=")?;
+                let z = range("z=")?;
+
+                Ok(Step {
+                    on,
+                    cuboid: Cuboid([x, y, z]),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// Only the part of each step inside the initialization area counts
+    fn run_p1(&self) -> usize {
+        let init = Cuboid([Interval::new(-50, 50); 3]);
+        let steps = self
+            .steps
+            .iter()
+            .filter_map(|e| {
+                e.cuboid
+                    .intersection(&init)
+                    .map(|cuboid| Step { on: e.on, cuboid })
+            })
+            .collect::<Vec<Step>>();
+        run(&steps)
+    }
+
+    fn run_p2(&self) -> usize {
+        run(&self.steps)
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        let mut map: HashSet<(isize, isize, isize)> = HashSet::new();
+
+        for step in &self.steps {
+            let [x, y, z] = step.cuboid.0;
+            for x in x.start.max(-50)..=x.end.min(50) {
+                for y in y.start.max(-50)..=y.end.min(50) {
+                    for z in z.start.max(-50)..=z.end.min(50) {
+                        if step.on {
+                            map.insert((x, y, z));
+                        } else {
+                            map.remove(&(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(map.len())
+    }
+
+    /// Compresses the coordinates to the cuboid edges and switches whole
+    /// blocks on and off, too slow and memory hungry for the real input.
+    fn reference_p2(&self) -> Option<usize> {
+        let edges = |axis: usize| {
+            let mut edges: Vec<isize> = self
+                .steps
+                .iter()
+                .flat_map(|e| [e.cuboid.0[axis].start, e.cuboid.0[axis].end + 1])
+                .collect();
+            edges.sort_unstable();
+            edges.dedup();
+            edges
+        };
+        let (xs, ys, zs) = (edges(0), edges(1), edges(2));
+        let find = |edges: &Vec<isize>, v: isize| edges.binary_search(&v).unwrap();
+
+        let mut grid = vec![false; xs.len() * ys.len() * zs.len()];
+        for step in &self.steps {
+            let [x, y, z] = step.cuboid.0;
+            for x in find(&xs, x.start)..find(&xs, x.end + 1) {
+                for y in find(&ys, y.start)..find(&ys, y.end + 1) {
+                    for z in find(&zs, z.start)..find(&zs, z.end + 1) {
+                        grid[(x * ys.len() + y) * zs.len() + z] = step.on;
+                    }
+                }
+            }
+        }
+
+        let mut count = 0;
+        for x in 0..xs.len() - 1 {
+            for y in 0..ys.len() - 1 {
+                for z in 0..zs.len() - 1 {
+                    if grid[(x * ys.len() + y) * zs.len() + z] {
+                        count += (xs[x + 1] - xs[x]) * (ys[y + 1] - ys[y]) * (zs[z + 1] - zs[z]);
+                    }
+                }
+            }
+        }
+
+        Some(count as usize)
+    }
+}
+
+/// The first cuboids stay inside the initialization area like in the real
+/// input, the rest are spread out over a much larger region without touching
+/// the initialization area.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let range = |rng: &mut GenRng, (min, max): (isize, isize), max_len: isize| {
+        let len = rng.gen_range(0..=max_len);
+        let lo = rng.gen_range(min..=max - len);
+        (lo, lo + len)
+    };
+    let outside = |r: (isize, isize)| r.0 > 50 || r.1 < -50;
+
+    (0..size.max(1))
+        .map(|i| {
+            let (area, max_len) = if i < 20 {
+                ((-50, 50), 50)
+            } else {
+                ((-100_000, 100_000), 50_000)
+            };
+
+            let state = if i == 0 || rng.gen_bool(0.6) {
+                "on"
+            } else {
+                "off"
+            };
+            let (x, y, z) = loop {
+                let xyz = (
+                    range(rng, area, max_len),
+                    range(rng, area, max_len),
+                    range(rng, area, max_len),
+                );
+                if i < 20 || outside(xyz.0) || outside(xyz.1) || outside(xyz.2) {
+                    break xyz;
+                }
+            };
+
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                state, x.0, x.1, y.0, y.1, z.0, z.1
+            )
+        })
+        .collect()
+}
+
+/// Keeps the lit cubes as disjoint cuboids and counts them at the end
+fn run(steps: &[Step]) -> usize {
+    let mut lit = CuboidSet::new();
+    for step in steps {
+        crate::cancel::checkpoint();
+        if step.on {
+            lit.insert(step.cuboid);
+        } else {
+            lit.remove(&step.cuboid);
+        }
+    }
+    lit.volume()
+}
+
+mod tests {
+    #[test]
+    fn test_cuboid_overlaps() {
+        use super::*;
+
+        let cube = |lo, hi| Cuboid([Interval::new(lo, hi); 3]);
+        assert!(cube(10, 12).overlaps(&cube(11, 13)));
+    }
+
+    #[test]
+    fn test_cuboid_dont_overlaps() {
+        use super::*;
+
+        let cube = |lo, hi| Cuboid([Interval::new(lo, hi); 3]);
+        let ca = cube(10, 12);
+
+        assert!(!ca.overlaps(&cube(13, 16)));
+        assert!(!ca.overlaps(&cube(5, 9)));
+    }
+
+    #[test]
+    fn test_cuboid_count() {
+        use super::*;
+
+        assert_eq!(27, Cuboid([Interval::new(10, 12); 3]).volume());
+    }
+
+    #[test]
+    fn test_cuboid_union() {
+        use super::*;
+
+        let mut set = CuboidSet::new();
+        set.insert(Cuboid([Interval::new(10, 12); 3]));
+        set.insert(Cuboid([Interval::new(11, 13); 3]));
+
+        assert_eq!(27 + 19, set.volume());
+    }
+}
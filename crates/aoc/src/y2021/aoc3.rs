@@ -1,4 +1,7 @@
+use crate::diagnostic::{line, lines, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::Rng;
 use std::rc::Rc;
 
 #[derive(Default)]
@@ -7,14 +10,22 @@ pub struct AOC3 {
 }
 
 impl Runner for AOC3 {
-	fn parse(&mut self, input: &Vec<String>) {
-		self.parsed = (
-			input[0].len(),
-			input
-				.iter()
-				.map(|e| u32::from_str_radix(&e.chars().collect::<String>(), 2).unwrap())
-				.collect(),
-		);
+	fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+		let len = line(input, 0)?.text.len();
+		let numbers = lines(input)
+			.map(|l| match l.text.char_indices().find(|(_, c)| *c != '0' && *c != '1') {
+				Some((i, c)) => Err(l.error_at(i, c.len_utf8(), format!("expected `0` or `1`, found `{}`", c))),
+				None if l.text.is_empty() => Err(l.error(l.text, "expected a binary number, found nothing")),
+				None if l.text.len() != len => Err(l
+					.error(l.text, format!("expected {} bits, found {}", len, l.text.len()))
+					.with_hint("every number needs as many bits as the first one")),
+				None => u32::from_str_radix(l.text, 2)
+					.map_err(|_| l.error(l.text, format!("expected at most 32 bits, found {}", len))),
+			})
+			.collect::<Result<_, _>>()?;
+
+		self.parsed = (len, numbers);
+		Ok(())
 	}
 
 	fn run_p1(&self) -> usize {
@@ -102,6 +113,19 @@ impl Runner for AOC3 {
 	}
 }
 
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let width = 12;
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{:0width$b}",
+                rng.gen_range(0..1u32 << width),
+                width = width
+            )
+        })
+        .collect()
+}
+
 fn count_bits_p(input: &Vec<&u32>, pos: u32) -> isize {
 	let cnt = input.iter().fold((0isize, 0isize), |acc, &&e| {
 		if bit_set(e, pos) {
@@ -1,4 +1,7 @@
+use crate::diagnostic::{line, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::seq::SliceRandom;
 
 #[derive(Default)]
 pub struct AOC4 {
@@ -67,22 +70,27 @@ struct Bingo {
 }
 
 impl Runner for AOC4 {
-    fn parse(&mut self, input: &Vec<String>) {
-        let draws: Vec<u8> = input[0]
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let first = line(input, 0)?;
+        let draws: Vec<u8> = first
+            .text
             .split_terminator(",")
-            .map(|e| e.parse::<u8>().unwrap())
-            .collect();
+            .map(|e| first.number::<u8>(e))
+            .collect::<Result<_, _>>()?;
 
         let mut boards: Vec<Board> = Vec::new();
         for i in 0..((input.len() - 1) / 6) {
             let mut data = [0u8; 25];
             for j in 1..6 {
-                for (idx, v) in input[i * 6 + j + 1]
-                    .split_whitespace()
-                    .map(|e| e.parse::<u8>().unwrap())
-                    .enumerate()
-                {
-                    data[idx + (j - 1) * 5] = v;
+                let l = line(input, i * 6 + j + 1)?;
+                let row = l.text.split_whitespace().collect::<Vec<&str>>();
+                if row.len() != 5 {
+                    return Err(l
+                        .error(l.text, format!("expected 5 numbers, found {}", row.len()))
+                        .with_hint("boards are 5 rows of 5 numbers"));
+                }
+                for (idx, v) in row.into_iter().enumerate() {
+                    data[idx + (j - 1) * 5] = l.number::<u8>(v)?;
                 }
             }
             boards.push(Board {
@@ -95,6 +103,7 @@ impl Runner for AOC4 {
             draws: draws,
             boards: boards,
         };
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
@@ -156,6 +165,33 @@ impl Runner for AOC4 {
     }
 }
 
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let mut draws: Vec<u8> = (0..100).collect();
+    draws.shuffle(rng);
+
+    let mut lines = vec![draws
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>()
+        .join(",")];
+
+    for _ in 0..size.max(1) {
+        let data: Vec<u8> = draws.choose_multiple(rng, 25).cloned().collect();
+
+        lines.push(String::new());
+        for row in data.chunks(5) {
+            lines.push(
+                row.iter()
+                    .map(|e| format!("{:2}", e))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+            );
+        }
+    }
+
+    lines
+}
+
 mod tests {
     /* bingo.boards[0].check_num(22);
     bingo.boards[0].check_num(13);
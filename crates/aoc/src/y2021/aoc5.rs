@@ -1,4 +1,9 @@
+use crate::diagnostic::{self, ParseError};
+use crate::generate::GenRng;
+use crate::grid::{Grid, SparseGrid};
 use crate::runner::Runner;
+use rand::Rng;
+use std::collections::HashMap;
 
 #[derive(Default)]
 pub struct AOC5 {
@@ -6,12 +11,12 @@ pub struct AOC5 {
 }
 
 impl AOC5 {
-    fn parse_point(p: &str) -> Point {
-        let (s, e) = p.split_once(",").unwrap();
-        Point {
-            x: s.parse::<isize>().unwrap(),
-            y: e.parse::<isize>().unwrap(),
-        }
+    fn parse_point(l: &diagnostic::Line, p: &str) -> Result<Point, ParseError> {
+        let (s, e) = l.split_once(p, ",")?;
+        Ok(Point {
+            x: l.number::<isize>(s)?,
+            y: l.number::<isize>(e)?,
+        })
     }
 }
 
@@ -107,42 +112,28 @@ impl Line {
 #[derive(Default, Debug)]
 struct Map {
     vents: Vec<Line>,
-    size: (usize, usize),
-}
-
-impl Map {
-    fn to_1d(&self, point: &Point) -> usize {
-        (point.x + self.size.0 as isize * point.y) as usize
-    }
 }
 
 impl Runner for AOC5 {
-    fn parse(&mut self, input: &Vec<String>) {
-        let mut w = 0usize;
-        let mut h = 0usize;
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
         let mut vents = Vec::new();
 
-        for l in input.iter() {
-            let (p1, p2) = l.split_once(" -> ").unwrap();
-            let p1 = Self::parse_point(p1);
-            let p2 = Self::parse_point(p2);
-
-            w = w.max(p1.x.max(p2.x) as usize);
-            h = h.max(p1.y.max(p2.y) as usize);
+        for l in diagnostic::lines(input) {
+            let (p1, p2) = l.split_once(l.text, " -> ")?;
+            let p1 = Self::parse_point(&l, p1)?;
+            let p2 = Self::parse_point(&l, p2)?;
 
             vents.push(Line { p1: p1, p2: p2 });
         }
 
-        self.parsed = Map {
-            vents: vents,
-            size: (w + 1, h + 1),
-        };
+        self.parsed = Map { vents };
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
         let map = &self.parsed;
 
-        let mut overlaps = vec![0u8; map.size.0 * map.size.1];
+        let mut overlaps = SparseGrid::new(0u8);
         let mut points = Vec::new();
 
         map.vents.iter().filter(|e| e.is_cardinal()).fold(0usize, |mut acc, vent| {
@@ -150,8 +141,9 @@ impl Runner for AOC5 {
             vent.get_points_cardinal(&mut points);
 
             for p in &points {
-                overlaps[map.to_1d(p)] += 1;
-                if overlaps[map.to_1d(p)] == 2 {
+                let overlap = overlaps.get_mut((p.x, p.y));
+                *overlap += 1;
+                if *overlap == 2 {
                     acc += 1;
                 }
             }
@@ -161,7 +153,7 @@ impl Runner for AOC5 {
     fn run_p2(&self) -> usize {
         let map = &self.parsed;
 
-        let mut overlaps = vec![0u8; map.size.0 * map.size.1];
+        let mut overlaps = SparseGrid::new(0u8);
         let mut points = Vec::new();
 
         map.vents.iter().fold(0usize, |mut acc, vent| {
@@ -169,34 +161,83 @@ impl Runner for AOC5 {
             vent.get_points(&mut points);
 
             for p in &points {
-                overlaps[map.to_1d(p)] += 1;
-                if overlaps[map.to_1d(p)] == 2 {
+                let overlap = overlaps.get_mut((p.x, p.y));
+                *overlap += 1;
+                if *overlap == 2 {
                     acc += 1;
                 }
             }
             acc
         })
     }
+
+    fn reference_p1(&self) -> Option<usize> {
+        Some(overlaps_reference(&self.parsed.vents, false))
+    }
+
+    fn reference_p2(&self) -> Option<usize> {
+        Some(overlaps_reference(&self.parsed.vents, true))
+    }
 }
 
-mod tests {
-    #[test]
-    fn map_2d_to_1d() {
-        let map = super::Map {
-            vents: Vec::new(),
-            size: (10, 10),
-        };
+/// Walks every vent a point at a time, counting how often each point is
+/// covered in a plain map.
+fn overlaps_reference(vents: &[Line], diagonals: bool) -> usize {
+    let mut covered: HashMap<(isize, isize), usize> = HashMap::new();
 
-        let p = super::Point { x: 5, y: 5 };
-        assert_eq!(55, map.to_1d(&p));
+    for vent in vents.iter().filter(|e| diagonals || e.is_cardinal()) {
+        let (dx, dy) = (
+            (vent.p2.x - vent.p1.x).signum(),
+            (vent.p2.y - vent.p1.y).signum(),
+        );
+        let (mut x, mut y) = (vent.p1.x, vent.p1.y);
+        loop {
+            *covered.entry((x, y)).or_insert(0) += 1;
+            if (x, y) == (vent.p2.x, vent.p2.y) {
+                break;
+            }
+            x += dx;
+            y += dy;
+        }
+    }
 
-        let p = super::Point { x: 5, y: 4 };
-        assert_eq!(45, map.to_1d(&p));
+    covered.values().filter(|&&e| e >= 2).count()
+}
 
-        let p = super::Point { x: 0, y: 3 };
-        assert_eq!(30, map.to_1d(&p));
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let extent = 1000isize;
+    let mut lines = Vec::new();
+
+    while lines.len() < size {
+        let p1 = Point::new(rng.gen_range(0..extent), rng.gen_range(0..extent));
+        let (sx, sy) = match rng.gen_range(0..3) {
+            0 => (if rng.gen_bool(0.5) { 1 } else { -1 }, 0),
+            1 => (0, if rng.gen_bool(0.5) { 1 } else { -1 }),
+            _ => (
+                if rng.gen_bool(0.5) { 1 } else { -1 },
+                if rng.gen_bool(0.5) { 1 } else { -1 },
+            ),
+        };
+
+        let room = |p: isize, s: isize| match s {
+            1 => extent - 1 - p,
+            -1 => p,
+            _ => isize::MAX,
+        };
+        let max_len = room(p1.x, sx).min(room(p1.y, sy)).min(extent / 3);
+        if max_len < 1 {
+            continue;
+        }
+
+        let len = rng.gen_range(1..=max_len);
+        let p2 = p1 + Point::new(sx * len, sy * len);
+        lines.push(format!("{},{} -> {},{}", p1.x, p1.y, p2.x, p2.y));
     }
 
+    lines
+}
+
+mod tests {
     /* #[test]
     fn bresenham_points() {
         use super::*;
@@ -0,0 +1,109 @@
+use crate::diagnostic::{line, ParseError};
+use crate::generate::GenRng;
+use crate::runner::{parse_setting, Runner};
+use crate::sim::Simulation;
+use rand::Rng;
+
+#[derive(Default)]
+pub struct AOC6 {
+    parsed: Vec<i8>,
+    /// overrides the number of days simulated by both parts
+    steps: Option<usize>,
+}
+
+impl Runner for AOC6 {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let l = line(input, 0)?;
+        self.parsed = l
+            .text
+            .split_terminator(",")
+            .map(|e| l.number::<i8>(e))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        let mut school = School::new(&self.parsed);
+        school.run(self.steps.unwrap_or(80));
+        school.observe()
+    }
+    fn run_p2(&self) -> usize {
+        let mut school = School::new(&self.parsed);
+        school.run(self.steps.unwrap_or(256));
+        school.observe()
+    }
+
+    fn reference_p1(&self) -> Option<usize> {
+        let mut fish = self.parsed.clone();
+
+        for _ in 0..self.steps.unwrap_or(80) {
+            let mut born = 0;
+            for f in fish.iter_mut() {
+                if *f == 0 {
+                    *f = 6;
+                    born += 1;
+                } else {
+                    *f -= 1;
+                }
+            }
+            fish.extend(std::iter::repeat(8).take(born));
+        }
+
+        Some(fish.len())
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "steps" => self.steps = Some(parse_setting(key, value)?),
+            _ => return Err(format!("Unknown setting {}", key)),
+        }
+        Ok(())
+    }
+}
+
+/// Number of fish by days left until they spawn
+struct School([usize; 9]);
+
+impl School {
+    fn new(fish: &[i8]) -> School {
+        let mut buckets = [0usize; 9];
+        for p in fish {
+            buckets[*p as usize] += 1;
+        }
+        School(buckets)
+    }
+}
+
+impl Simulation for School {
+    fn step(&mut self) {
+        let buckets = &mut self.0;
+        let fst = buckets[0];
+        buckets.rotate_left(1);
+        buckets[6] += fst;
+        buckets[8] = fst;
+    }
+
+    fn observe(&self) -> usize {
+        self.0.iter().sum()
+    }
+}
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    vec![(0..size.max(1))
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect::<Vec<String>>()
+        .join(",")]
+}
+
+mod tests {
+    #[test]
+    fn reference_uses_steps() {
+        use super::*;
+
+        let mut aoc = AOC6::default();
+        aoc.parse(&vec![String::from("3,4,3,1,2")]).unwrap();
+        aoc.set("steps", "18").unwrap();
+        assert_eq!(26, aoc.run_p1());
+        assert_eq!(Some(26), aoc.reference_p1());
+    }
+}
@@ -1,4 +1,7 @@
+use crate::diagnostic::{line, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::Rng;
 
 #[derive(Default)]
 pub struct AOC7 {
@@ -10,11 +13,14 @@ fn sum(num: isize) -> isize {
 }
 
 impl Runner for AOC7 {
-    fn parse(&mut self, input: &Vec<String>) {
-        self.parsed = input[0]
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let l = line(input, 0)?;
+        self.parsed = l
+            .text
             .split_terminator(",")
-            .map(|e| e.parse::<isize>().unwrap())
-            .collect()
+            .map(|e| l.number::<isize>(e))
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
 
     fn run_p1(&self) -> usize {
@@ -49,3 +55,11 @@ impl Runner for AOC7 {
         min_cost as usize
     }
 }
+
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    vec![(0..size)
+        .map(|_| rng.gen_range(0..size * 2).to_string())
+        .collect::<Vec<String>>()
+        .join(",")]
+}
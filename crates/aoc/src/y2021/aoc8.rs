@@ -1,4 +1,7 @@
+use crate::diagnostic::{self, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::seq::SliceRandom;
 
 #[derive(Default)]
 pub struct AOC8 {
@@ -51,28 +54,33 @@ fn to_digit(input: &[u8]) -> u8 {
     }
 }
 
-fn parse_digits(input: &str) -> Vec<Vec<u8>> {
+fn parse_digits(line: &diagnostic::Line, input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     input
         .split_terminator(" ")
         .map(|v| {
-            v.chars().map(|c| to_segment_id(&c)).collect::<Vec<u8>>()
+            match v.char_indices().find(|(_, c)| !('a'..='g').contains(c)) {
+                Some((i, c)) => Err(line.error(
+                    &v[i..i + c.len_utf8()],
+                    format!("expected a segment `a` to `g`, found `{}`", c),
+                )),
+                None => Ok(v.chars().map(|c| to_segment_id(&c)).collect::<Vec<u8>>()),
+            }
         })
         .collect()
 }
 
 impl Runner for AOC8 {
-    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) {
-        self.parsed = input
-            .iter()
-            .map(|e| {
-                e.split_once(" | ")
-                    .map(|(l, r)| Line {
-                        left_part: parse_digits(l),
-                        right_part: parse_digits(r),
-                    })
-                    .unwrap()
+    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) -> Result<(), ParseError> {
+        self.parsed = diagnostic::lines(input)
+            .map(|l| {
+                let (left, right) = l.split_once(l.text, " | ")?;
+                Ok(Line {
+                    left_part: parse_digits(&l, left)?,
+                    right_part: parse_digits(&l, right)?,
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+        Ok(())
     }
     fn run_p1(&self) -> usize {
         let wanted = [2, 3, 4, 7];
@@ -91,6 +99,35 @@ impl Runner for AOC8 {
     }
 }
 
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let digits = [ZERO, ONE, TWO, THREE, FOUR, FIVE, SIX, SEVEN, EIGHT, NINE];
+
+    let to_signal = |rng: &mut GenRng, wiring: &[char], digit: &[u8]| {
+        let mut signal: Vec<char> = digit.iter().map(|&e| wiring[e as usize]).collect();
+        signal.shuffle(rng);
+        signal.into_iter().collect::<String>()
+    };
+
+    (0..size)
+        .map(|_| {
+            let mut wiring: Vec<char> = "abcdefg".chars().collect();
+            wiring.shuffle(rng);
+
+            let mut left: Vec<String> = digits.iter().map(|e| to_signal(rng, &wiring, e)).collect();
+            left.shuffle(rng);
+
+            let right: Vec<String> = (0..4)
+                .map(|_| {
+                    let digit = *digits.choose(rng).unwrap();
+                    to_signal(rng, &wiring, digit)
+                })
+                .collect();
+
+            format!("{} | {}", left.join(" "), right.join(" "))
+        })
+        .collect()
+}
+
 fn solve_line(line: &Line) -> usize {
     let lookup = solve_signal(line);
     let mut digit = Vec::new();
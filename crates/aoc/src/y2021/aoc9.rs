@@ -1,4 +1,7 @@
+use crate::diagnostic::{digit_grid, ParseError};
+use crate::generate::GenRng;
 use crate::runner::Runner;
+use rand::Rng;
 use std::collections::HashSet;
 use std::ops::Add;
 
@@ -112,21 +115,13 @@ impl AOC9 {
 }
 
 impl Runner for AOC9 {
-    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) {
-        let h = input.len();
-        let w = input[0].len();
-        let mut map = vec![vec![255u8; w]; h];
-
-        for y in 0..h {
-            let l = &input[y];
-            for (x, c) in l.chars().enumerate() {
-                map[y][x] = (c as u8) - 48;
-            }
-        }
+    fn parse(&mut self, input: &std::vec::Vec<std::string::String>) -> Result<(), ParseError> {
+        let map = digit_grid(input)?;
 
-        self.w = w as isize;
-        self.h = h as isize;
+        self.w = map[0].len() as isize;
+        self.h = map.len() as isize;
         self.parsed = map;
+        Ok(())
     }
     fn run_p1(&self) -> usize {
         let low_points = self.find_low_points();
@@ -153,3 +148,47 @@ impl Runner for AOC9 {
             .fold(1usize, |acc, e| acc * e)
     }
 }
+
+/// Heights grow with the distance to a set of seeded low points, with walls
+/// of 9s where two basins meet.
+pub fn generate(rng: &mut GenRng, size: usize) -> Vec<String> {
+    let size = size.max(3) as isize;
+    let wanted = 3.max(size * size / 50);
+
+    let mut seeds: Vec<Point> = vec![Point(0, 0), Point(size - 1, 0), Point(0, size - 1)];
+    for _ in 0..wanted * 10 {
+        if seeds.len() as isize >= wanted {
+            break;
+        }
+        let p = Point(rng.gen_range(0..size), rng.gen_range(0..size));
+        if seeds
+            .iter()
+            .all(|s| (s.0 - p.0).abs() + (s.1 - p.1).abs() > 1)
+        {
+            seeds.push(p);
+        }
+    }
+
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let (d1, d2) = seeds.iter().fold((isize::MAX, isize::MAX), |acc, s| {
+                        let d = (s.0 - x).abs() + (s.1 - y).abs();
+                        if d < acc.0 {
+                            (d, acc.0)
+                        } else {
+                            (acc.0, acc.1.min(d))
+                        }
+                    });
+
+                    if d1 > 0 && d2 - d1 <= 1 {
+                        '9'
+                    } else {
+                        (b'0' + d1.min(8) as u8) as char
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+//! Solvers for Advent of Code 2021.
+
+use crate::runner::Runner;
+
+pub mod aoc1;
+pub mod aoc10;
+pub mod aoc11;
+pub mod aoc12;
+pub mod aoc13;
+pub mod aoc14;
+pub mod aoc15;
+pub mod aoc16;
+pub mod aoc17;
+pub mod aoc18;
+pub mod aoc19;
+pub mod aoc2;
+pub mod aoc20;
+pub mod aoc21;
+pub mod aoc22;
+pub mod aoc3;
+pub mod aoc4;
+pub mod aoc5;
+pub mod aoc6;
+pub mod aoc7;
+pub mod aoc8;
+pub mod aoc9;
+
+pub fn get_runner(day: usize) -> Option<Box<dyn Runner>> {
+    let runner: Box<dyn Runner> = match day {
+        1 => Box::new(aoc1::AOC1::default()),
+        2 => Box::new(aoc2::AOC2::default()),
+        3 => Box::new(aoc3::AOC3::default()),
+        4 => Box::new(aoc4::AOC4::default()),
+        5 => Box::new(aoc5::AOC5::default()),
+        6 => Box::new(aoc6::AOC6::default()),
+        7 => Box::new(aoc7::AOC7::default()),
+        8 => Box::new(aoc8::AOC8::default()),
+        9 => Box::new(aoc9::AOC9::default()),
+        10 => Box::new(aoc10::AOC10::default()),
+        11 => Box::new(aoc11::AOC11::default()),
+        12 => Box::new(aoc12::AOC12::default()),
+        13 => Box::new(aoc13::AOC13::default()),
+        14 => Box::new(aoc14::AOC14::default()),
+        15 => Box::new(aoc15::AOC15::default()),
+        16 => Box::new(aoc16::AOC16::default()),
+        17 => Box::new(aoc17::AOC17::default()),
+        18 => Box::new(aoc18::AOC18::default()),
+        19 => Box::new(aoc19::AOC19::default()),
+        20 => Box::new(aoc20::AOC20::default()),
+        21 => Box::new(aoc21::AOC21::default()),
+        22 => Box::new(aoc22::AOC22::default()),
+        _ => return None,
+    };
+    Some(runner)
+}
+
+pub fn solver_source(day: usize) -> Option<&'static str> {
+    let source = match day {
+        1 => include_str!("aoc1.rs"),
+        2 => include_str!("aoc2.rs"),
+        3 => include_str!("aoc3.rs"),
+        4 => include_str!("aoc4.rs"),
+        5 => include_str!("aoc5.rs"),
+        6 => include_str!("aoc6.rs"),
+        7 => include_str!("aoc7.rs"),
+        8 => include_str!("aoc8.rs"),
+        9 => include_str!("aoc9.rs"),
+        10 => include_str!("aoc10.rs"),
+        11 => include_str!("aoc11.rs"),
+        12 => include_str!("aoc12.rs"),
+        13 => include_str!("aoc13.rs"),
+        14 => include_str!("aoc14.rs"),
+        15 => include_str!("aoc15.rs"),
+        16 => include_str!("aoc16.rs"),
+        17 => include_str!("aoc17.rs"),
+        18 => include_str!("aoc18.rs"),
+        19 => include_str!("aoc19.rs"),
+        20 => include_str!("aoc20.rs"),
+        21 => include_str!("aoc21.rs"),
+        22 => include_str!("aoc22.rs"),
+        _ => return None,
+    };
+    Some(source)
+}
+
+/// Modules every solver goes through, parsing, running and cancelling
+const SHARED: [&str; 3] = [
+    include_str!("../runner.rs"),
+    include_str!("../diagnostic.rs"),
+    include_str!("../cancel.rs"),
+];
+
+const SIM: &str = include_str!("../sim.rs");
+const INTERVALS: &str = include_str!("../intervals.rs");
+const GRID: &str = include_str!("../grid.rs");
+const HASH: &str = include_str!("../hash.rs");
+
+/// Shared modules a day uses on top of [`SHARED`]
+fn dependencies(day: usize) -> &'static [&'static str] {
+    match day {
+        5 | 13 => &[GRID, INTERVALS, HASH],
+        6 | 11 => &[SIM],
+        12 | 19 => &[HASH],
+        17 | 22 => &[INTERVALS],
+        20 => &[SIM, GRID, INTERVALS, HASH],
+        21 => &[SIM, HASH],
+        _ => &[],
+    }
+}
+
+/// Source of a day's solver and of every module it depends on
+pub fn solver_sources(day: usize) -> Option<Vec<&'static str>> {
+    let mut sources = vec![solver_source(day)?];
+    sources.extend(SHARED);
+    sources.extend(dependencies(day));
+    Some(sources)
+}
+
+pub fn input_format(day: usize) -> Option<&'static str> {
+    let format = match day {
+        1 => "one depth per line, like `199`",
+        2 => "one command per line, like `forward 5`, `down 5` or `up 3`",
+        3 => "one binary number per line, like `00100`",
+        4 => "comma separated draws, then 5x5 boards each after a blank line",
+        5 => "one vent per line, like `0,9 -> 5,9`",
+        6 => "comma separated timers on one line, like `3,4,3,1,2`",
+        7 => "comma separated positions on one line, like `16,1,2,0,4`",
+        8 => "ten patterns, ` | ` and four digits per line, like `be cfbegad ... | fdgacbe cefdb cefbgd gcbe`",
+        9 => "rows of single digit heights, like `2199943210`",
+        10 => "one line of brackets per line, like `[({(<(())[]>[[{[]{<()<>>`",
+        11 => "rows of single digit energy levels, like `5483143223`",
+        12 => "one connection per line, like `start-A`",
+        13 => "dots like `6,10`, a blank line, then folds like `fold along y=7`",
+        14 => "the polymer template, a blank line, then rules like `CH -> B`",
+        15 => "rows of single digit risk levels, like `1163751742`",
+        16 => "the transmission in hexadecimal on one line, like `8A004A801A8002F478`",
+        17 => "the target area on one line, like `target area: x=20..30, y=-10..-5`",
+        18 => "one snailfish number per line, like `[[1,2],3]`",
+        19 => "scanners headed by `--- scanner 0 ---` with one beacon like `404,-588,-901` per line, separated by blank lines",
+        20 => "the enhancement algorithm of `#` and `.`, a blank line, then the image",
+        21 => "two lines like `Player 1 starting position: 4`",
+        22 => "one reboot step per line, like `on x=10..12,y=10..12,z=10..12`",
+        _ => return None,
+    };
+    Some(format)
+}
+
+mod tests {
+    #[test]
+    fn sources_cover_imports() {
+        use super::*;
+
+        let modules = [
+            ("cancel", SHARED[2]),
+            ("sim", SIM),
+            ("intervals", INTERVALS),
+            ("grid", GRID),
+            ("hash", HASH),
+        ];
+        for day in 1..=22 {
+            let sources = solver_sources(day).unwrap();
+            for source in &sources {
+                for (name, module) in modules {
+                    if source.contains(&format!("crate::{}::", name)) {
+                        assert!(
+                            sources.contains(&module),
+                            "day {} doesn't hash {}.rs",
+                            day,
+                            name
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
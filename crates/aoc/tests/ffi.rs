@@ -0,0 +1,64 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::PathBuf;
+
+use libloading::{Library, Symbol};
+
+type SolveFn = unsafe extern "C" fn(u32, u32, u32, *const u8, usize) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+type LastErrorFn = unsafe extern "C" fn() -> *const c_char;
+
+/// The cdylib ends up next to the test binary or one directory up from it
+fn library_path() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    let name = libloading::library_filename("aoc");
+
+    [deps, deps.parent().unwrap()]
+        .iter()
+        .map(|dir| dir.join(&name))
+        .find(|path| path.exists())
+        .expect("Couldnt find the aoc shared library")
+}
+
+#[test]
+fn solve_through_c_api() {
+    let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    unsafe {
+        let lib = Library::new(library_path()).unwrap();
+        let solve: Symbol<SolveFn> = lib.get(b"aoc_solve").unwrap();
+        let free: Symbol<FreeFn> = lib.get(b"aoc_free_string").unwrap();
+        let last_error: Symbol<LastErrorFn> = lib.get(b"aoc_last_error").unwrap();
+
+        let answer = solve(2021, 1, 2, input.as_ptr(), input.len());
+        assert!(!answer.is_null());
+        assert_eq!("5", CStr::from_ptr(answer).to_str().unwrap());
+        free(answer);
+        assert!(last_error().is_null());
+
+        let answer = solve(2021, 99, 1, input.as_ptr(), input.len());
+        assert!(answer.is_null());
+        assert_eq!(
+            "Runner for day 99 not implemented",
+            CStr::from_ptr(last_error()).to_str().unwrap()
+        );
+
+        let answer = solve(1999, 1, 1, input.as_ptr(), input.len());
+        assert!(answer.is_null());
+        assert_eq!(
+            "No solvers for year 1999",
+            CStr::from_ptr(last_error()).to_str().unwrap()
+        );
+    }
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+    assert_eq!(
+        generated,
+        include_str!("../include/aoc.h"),
+        "include/aoc.h is stale, copy the header from OUT_DIR over it"
+    );
+}
@@ -0,0 +1,41 @@
+use aoc::crypt::{encrypt, generate_key, parse_key, read_encrypted_with};
+use aoc::input::*;
+
+/// Runs in its own process, moving the resources only affects this test
+#[test]
+fn profiles_and_answers() {
+    let dir = std::env::temp_dir().join(format!("aoc_input_test_{}", std::process::id()));
+    set_resources_dir(dir.clone());
+
+    let profile = "test_profile";
+    for profile in [DEFAULT_PROFILE, profile] {
+        let path = input_path(2021, profile, 1);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "1\n2\n").unwrap();
+    }
+
+    let found = profiles(2021, 1);
+    assert_eq!(DEFAULT_PROFILE, found[0]);
+    assert!(found.contains(&profile.to_string()));
+    assert!(!profiles(2021, 2).contains(&profile.to_string()));
+
+    assert_eq!(Ok(None), get_answers(2021, profile, 1));
+    record_answers(2021, profile, 1, (1, 0)).unwrap();
+    assert_eq!(Ok(Some((1, 0))), get_answers(2021, profile, 1));
+
+    let key = parse_key(&generate_key(), "the test").unwrap();
+    let path = input_path(2021, profile, 1);
+    std::fs::write(encrypted_path(&path), encrypt(&key, b"1\n2\n")).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(profiles(2021, 1).contains(&profile.to_string()));
+    assert_eq!(
+        Ok(String::from("1\n2\n")),
+        read_encrypted_with(&key, &encrypted_path(&path))
+    );
+
+    // a stray plain text copy doesn't win over the encrypted input
+    std::fs::write(&path, "3\n").unwrap();
+    assert!(get_input(2021, profile, 1).unwrap_err().contains("Both"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+/// The example plugin is built into the examples directory next to `deps`
+fn plugin_path() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    let target = exe.parent().unwrap().parent().unwrap();
+    target
+        .join("examples")
+        .join(libloading::library_filename("plugin"))
+}
+
+#[test]
+fn load_example_plugin() {
+    let input: Vec<String> = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263"
+        .lines()
+        .map(String::from)
+        .collect();
+
+    let solvers = aoc::plugin::load(&plugin_path()).unwrap();
+    assert_eq!(1, solvers.len());
+    assert_eq!(
+        (2021, 1, "windows"),
+        (solvers[0].year, solvers[0].day, solvers[0].name.as_str())
+    );
+    assert!(aoc::plugin::load(&plugin_path()).is_err());
+
+    let mut runner = aoc::plugin::get_runner(2021, 1, "windows").unwrap();
+    runner.parse(&input).unwrap();
+    assert_eq!(7, runner.run_p1());
+    assert_eq!(5, runner.run_p2());
+
+    let error = runner.parse(&vec![String::from("19x")]).unwrap_err();
+    assert_eq!(Some((2021, 1)), error.context.day);
+    assert!(aoc::plugin::get_runner(2021, 2, "windows").is_none());
+}
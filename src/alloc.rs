@@ -0,0 +1,143 @@
+//! Counting global allocator, compiled in with the `alloc-stats` feature.
+//!
+//! The counters are process wide, allocations made by other threads while a
+//! phase runs are included in its numbers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn record_alloc(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    TOTAL.fetch_add(size, Ordering::Relaxed);
+    COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counted as a new allocation of `new_size` followed by freeing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_alloc(new_size);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Snapshot {
+    current: usize,
+    peak: usize,
+    total: usize,
+    count: usize,
+}
+
+fn snapshot() -> Snapshot {
+    Snapshot {
+        current: CURRENT.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed),
+        total: TOTAL.load(Ordering::Relaxed),
+        count: COUNT.load(Ordering::Relaxed),
+    }
+}
+
+/// Allocations made during a phase.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Stats {
+    /// most bytes held at once on top of what was live when the phase started
+    pub peak: usize,
+    pub total: usize,
+    pub count: usize,
+}
+
+impl Stats {
+    /// Combines the stats of a phase that is entered more than once
+    pub fn merge(&mut self, other: Stats) {
+        self.peak = self.peak.max(other.peak);
+        self.total += other.total;
+        self.count += other.count;
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {} | {} allocs | {} total",
+            bytes(self.peak),
+            self.count,
+            bytes(self.total)
+        )
+    }
+}
+
+fn bytes(n: usize) -> String {
+    match n {
+        n if n >= 1 << 20 => format!("{:.1} MiB", n as f64 / (1 << 20) as f64),
+        n if n >= 1 << 10 => format!("{:.1} KiB", n as f64 / (1 << 10) as f64),
+        n => format!("{} B", n),
+    }
+}
+
+/// Starts measuring a phase, the peak is reset to what is live right now.
+pub fn start_phase() -> Snapshot {
+    let start = snapshot();
+    PEAK.store(start.current, Ordering::Relaxed);
+    start
+}
+
+/// Stops measuring a phase, restoring the peak of any enclosing phase.
+pub fn end_phase(start: Snapshot) -> Stats {
+    let end = snapshot();
+    PEAK.fetch_max(start.peak, Ordering::Relaxed);
+
+    Stats {
+        peak: end.peak.saturating_sub(start.current),
+        total: end.total - start.total,
+        count: end.count - start.count,
+    }
+}
+
+mod tests {
+    #[test]
+    fn counts_phase_allocations() {
+        let start = super::start_phase();
+        let data = vec![0u8; 1 << 20];
+        drop(data);
+        let stats = super::end_phase(start);
+
+        assert!(stats.peak >= 1 << 20);
+        assert!(stats.total >= 1 << 20);
+        assert!(stats.count >= 1);
+    }
+}
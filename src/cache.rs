@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use aoc::runner::{fnv1a, solver_version};
+
+/// Answers stored on disk, one file per year, day, part, solver version and input.
+///
+/// Editing a solver, a module it depends on or bumping the crate version
+/// changes the solver version, so stale answers are never read again.
+pub struct Cache {
+    dir: Option<PathBuf>,
+}
+
+impl Cache {
+    /// A cache in `dir`, or one that always runs the solver for `None`
+    pub fn new(dir: Option<PathBuf>) -> Cache {
+        Cache { dir }
+    }
+
+    fn path(&self, year: usize, day: usize, part: usize, input: &[String]) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        let version = solver_version(year, day)?;
+        let input = fnv1a(input.join("\n").as_bytes());
+
+        Some(dir.join(format!(
+            "{}_day{}_p{}_{:016x}_{:016x}",
+            year, day, part, version, input
+        )))
+    }
+
+    /// Returns the cached answer, or runs the solver and stores its answer.
+    /// The flag tells if the answer came from the cache, failed runs are not
+    /// stored.
+    pub fn get_or_run<E>(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &[String],
+        run: impl FnOnce() -> Result<usize, E>,
+    ) -> Result<(usize, bool), E> {
+        let path = self.path(year, day, part, input);
+
+        let cached = path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| content.trim().parse::<usize>().ok());
+        if let Some(answer) = cached {
+            return Ok((answer, true));
+        }
+
+        let answer = run()?;
+        if let Some(path) = path {
+            let written = std::fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| std::fs::write(&path, answer.to_string()));
+            if let Err(e) = written {
+                eprintln!("Couldnt write cache file {}: {}", path.display(), e);
+            }
+        }
+
+        Ok((answer, false))
+    }
+}
+
+mod tests {
+    #[test]
+    fn cache_hits_same_input_only() {
+        let dir = std::env::temp_dir().join(format!("aoc_cache_test_{}", std::process::id()));
+        let cache = super::Cache::new(Some(dir.clone()));
+        let input = vec![String::from("3,4,3,1,2")];
+        let other = vec![String::from("3,4,3,1")];
+
+        let ok = |answer: usize| move || Ok::<usize, &str>(answer);
+
+        assert_eq!(
+            Ok((5934, false)),
+            cache.get_or_run(2021, 6, 1, &input, ok(5934))
+        );
+        assert_eq!(
+            Ok((5934, true)),
+            cache.get_or_run(2021, 6, 1, &input, || Err("ran again"))
+        );
+        assert_eq!(Ok((1, false)), cache.get_or_run(2021, 6, 2, &input, ok(1)));
+        assert_eq!(Ok((2, false)), cache.get_or_run(2021, 6, 1, &other, ok(2)));
+
+        // failed runs are not stored
+        assert_eq!(
+            Err("timed out"),
+            cache.get_or_run(2021, 6, 2, &other, || Err("timed out"))
+        );
+        assert_eq!(Ok((4, false)), cache.get_or_run(2021, 6, 2, &other, ok(4)));
+
+        let disabled = super::Cache::new(None);
+        assert_eq!(
+            Ok((3, false)),
+            disabled.get_or_run(2021, 6, 1, &input, ok(3))
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
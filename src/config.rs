@@ -0,0 +1,123 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Name of the project configuration file, looked up from the working
+/// directory upwards.
+pub const FILE_NAME: &str = "aoc.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Output {
+    Text,
+    /// Progress stays on stderr, the results are printed as JSON on stdout
+    Json,
+}
+
+/// Settings from `aoc.toml`, CLI flags override them after loading.
+///
+/// Relative paths in the file are relative to the directory it is in.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub resources: PathBuf,
+    pub assets: PathBuf,
+    /// Shared libraries with extra solvers, see `aoc::plugin`
+    pub plugins: PathBuf,
+    /// Rhai solutions run with `--script`, needs the `scripting` feature
+    pub scripts: PathBuf,
+    pub year: usize,
+    pub profile: String,
+    pub output: Output,
+    /// Budget for every part in seconds
+    pub timeout: u64,
+    /// Where answers are cached, `None` turns the cache off
+    pub cache: Option<PathBuf>,
+    /// Check the example before running a part on the puzzle inputs
+    pub examples: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            resources: PathBuf::from(aoc::input::RESOURCES),
+            assets: PathBuf::from(aoc::input::ASSETS),
+            plugins: PathBuf::from(aoc::plugin::PLUGINS),
+            scripts: PathBuf::from("./scripts"),
+            year: aoc::runner::DEFAULT_YEAR,
+            profile: aoc::input::DEFAULT_PROFILE.to_string(),
+            output: Output::Text,
+            timeout: 60,
+            cache: Some(PathBuf::from(".aoc_cache")),
+            examples: true,
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(content: &str, dir: &Path) -> Result<Config, String> {
+        let mut config: Config = toml::from_str(content).map_err(|e| e.to_string())?;
+
+        config.resources = dir.join(&config.resources);
+        config.assets = dir.join(&config.assets);
+        config.plugins = dir.join(&config.plugins);
+        config.scripts = dir.join(&config.scripts);
+        config.cache = config.cache.map(|cache| dir.join(cache));
+        Ok(config)
+    }
+
+    /// Loads the first `aoc.toml` found in `start` or one of its parents, the
+    /// defaults when there is none.
+    pub fn find(start: &Path) -> Result<Config, String> {
+        let path = match start
+            .ancestors()
+            .map(|e| e.join(FILE_NAME))
+            .find(|e| e.exists())
+        {
+            Some(path) => path,
+            None => return Ok(Config::default()),
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Couldnt read {}: {}", path.display(), e))?;
+        Config::parse(&content, path.parent().unwrap())
+            .map_err(|e| format!("Bad config in {}: {}", path.display(), e))
+    }
+
+    pub fn load() -> Result<Config, String> {
+        let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+        Config::find(&cwd)
+    }
+}
+
+mod tests {
+    #[test]
+    fn config_from_parent_dir() {
+        use super::*;
+
+        let root = std::env::temp_dir().join(format!("aoc_config_test_{}", std::process::id()));
+        let nested = root.join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(60, Config::find(&nested).unwrap().timeout);
+
+        std::fs::write(
+            root.join(FILE_NAME),
+            "resources = \"inputs\"\ntimeout = 5\noutput = \"json\"\n",
+        )
+        .unwrap();
+        let config = Config::find(&nested).unwrap();
+        assert_eq!(root.join("inputs"), config.resources);
+        assert_eq!(5, config.timeout);
+        assert_eq!(Output::Json, config.output);
+        assert_eq!(aoc::runner::DEFAULT_YEAR, config.year);
+        assert!(config.examples);
+
+        std::fs::write(root.join(FILE_NAME), "timeout = \"soon\"\n").unwrap();
+        assert!(Config::find(&nested).is_err());
+        std::fs::write(root.join(FILE_NAME), "colour = true\n").unwrap();
+        assert!(Config::find(&nested).is_err());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
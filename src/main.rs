@@ -1,140 +1,669 @@
-use aoc::runner::Runner;
-
-fn get_runner(day: usize) -> Box<dyn Runner> {
-    match day {
-        1 => Box::new(aoc::aoc1::AOC1::default()),
-        2 => Box::new(aoc::aoc2::AOC2::default()),
-        3 => Box::new(aoc::aoc3::AOC3::default()),
-        4 => Box::new(aoc::aoc4::AOC4::default()),
-        5 => Box::new(aoc::aoc5::AOC5::default()),
-        6 => Box::new(aoc::aoc6::AOC6::default()),
-        7 => Box::new(aoc::aoc7::AOC7::default()),
-        8 => Box::new(aoc::aoc8::AOC8::default()),
-        9 => Box::new(aoc::aoc9::AOC9::default()),
-        10 => Box::new(aoc::aoc10::AOC10::default()),
-        11 => Box::new(aoc::aoc11::AOC11::default()),
-        12 => Box::new(aoc::aoc12::AOC12::default()),
-        13 => Box::new(aoc::aoc13::AOC13::default()),
-        14 => Box::new(aoc::aoc14::AOC14::default()),
-        15 => Box::new(aoc::aoc15::AOC15::default()),
-        16 => Box::new(aoc::aoc16::AOC16::default()),
-        17 => Box::new(aoc::aoc17::AOC17::default()),
-        18 => Box::new(aoc::aoc18::AOC18::default()),
-        19 => Box::new(aoc::aoc19::AOC19::default()),
-        20 => Box::new(aoc::aoc20::AOC20::default()),
-        21 => Box::new(aoc::aoc21::AOC21::default()),
-        22 => Box::new(aoc::aoc22::AOC22::default()),
-        _ => panic!("Runner for day {} not implemented", day),
+use std::path::PathBuf;
+
+use aoc::cancel::with_budget;
+use aoc::runner::{PartStatus, Runner};
+use config::{Config, Output};
+use serde_json::json;
+
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod cache;
+mod config;
+mod repl;
+#[cfg(feature = "scripting")]
+mod script;
+mod server;
+mod trace;
+#[cfg(feature = "tui")]
+mod tui;
+mod watch;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: alloc::Counting = alloc::Counting;
+
+fn get_runner(year: usize, day: usize) -> Box<dyn Runner> {
+    match aoc::runner::get_runner(year, day) {
+        Some(runner) => runner,
+        None => panic!("Runner for {} day {} not implemented", year, day),
+    }
+}
+
+/// Which implementation of a day to run
+#[derive(Debug, Clone, PartialEq)]
+enum Solver {
+    Builtin,
+    /// A solver of a loaded plugin, by name
+    Plugin(String),
+    Script(PathBuf),
+}
+
+fn get_solver(year: usize, day: usize, solver: &Solver) -> Box<dyn Runner> {
+    match solver {
+        Solver::Builtin => get_runner(year, day),
+        Solver::Plugin(name) => match aoc::plugin::get_runner(year, day, name) {
+            Some(runner) => runner,
+            None => panic!("No plugin solver {} for {} day {}", name, year, day),
+        },
+        Solver::Script(path) => get_script(year, day, path),
     }
 }
 
+#[cfg(feature = "scripting")]
+fn get_script(year: usize, day: usize, path: &std::path::Path) -> Box<dyn Runner> {
+    match script::ScriptRunner::load(year, day, path) {
+        Ok(runner) => Box::new(runner),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+fn get_script(_year: usize, _day: usize, _path: &std::path::Path) -> Box<dyn Runner> {
+    panic!("Scripts need the scripting feature, build with --features scripting")
+}
+
+#[cfg(feature = "tui")]
+fn tui(config: &Config) {
+    tui::tui(config.year, std::time::Duration::from_secs(config.timeout));
+}
+
+#[cfg(not(feature = "tui"))]
+fn tui(_config: &Config) {
+    panic!("The dashboard needs the tui feature, build with --features tui")
+}
+
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+    let trace_options = trace::TraceOptions {
+        timings: take_flag(&mut args, "--timings"),
+        chrome: take_value(&mut args, "--chrome-trace"),
+        verbose: take_flag(&mut args, "--verbose"),
+    };
+    let _guard = trace::init(&trace_options);
+
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => panic!("{}", e),
+    };
+    if let Some(dir) = take_value(&mut args, "--cache-dir") {
+        config.cache = Some(PathBuf::from(dir));
+    }
+    if take_flag(&mut args, "--no-cache") {
+        config.cache = None;
+    }
+    if let Some(year) = take_value(&mut args, "--year") {
+        config.year = parse_flag("--year", Some(&year));
+    }
+    if let Some(dir) = take_value(&mut args, "--resources") {
+        config.resources = PathBuf::from(dir);
+    }
+    if let Some(dir) = take_value(&mut args, "--assets") {
+        config.assets = PathBuf::from(dir);
+    }
+    if let Some(dir) = take_value(&mut args, "--plugins") {
+        config.plugins = PathBuf::from(dir);
+    }
+    if let Some(dir) = take_value(&mut args, "--scripts") {
+        config.scripts = PathBuf::from(dir);
+    }
+    if let Some(format) = take_value(&mut args, "--format") {
+        config.output = match format.as_str() {
+            "text" => Output::Text,
+            "json" => Output::Json,
+            _ => panic!("expected text or json after --format"),
+        };
+    }
+    if !aoc::runner::YEARS.contains(&config.year) {
+        panic!("no solvers for year {}", config.year);
+    }
+    aoc::input::set_resources_dir(config.resources.clone());
+    aoc::input::set_assets_dir(config.assets.clone());
+    for (path, result) in aoc::plugin::load_dir(&config.plugins) {
+        if let Err(e) = result {
+            eprintln!("Skipping plugin {}: {}", path.display(), e);
+        }
+    }
+    let year = config.year;
+
     if args.len() == 1 {
         panic!("expected first argument to be day as number");
     }
-    let day = match args[1].parse::<usize>() {
-        Ok(day) => day,
-        Err(_) => panic!("expected first argument to be a number"),
-    };
 
-    let mut runner = get_runner(day);
-    let input = get_input(day);
-    let test_data = get_test_data(day);
+    match args[1].as_str() {
+        "generate" | "diff" if year != aoc::generate::YEAR => {
+            panic!("generators only exist for {}", aoc::generate::YEAR)
+        }
+        "generate" => generate(&args[2..]),
+        "diff" => diff(&args[2..]),
+        "serve" => serve(&args[2..], &config),
+        "repl" => repl::repl(year, parse_day(args.get(2)), args.get(3).cloned()),
+        "list" => list(year),
+        "tui" => tui(&config),
+        "encrypt-inputs" => encrypt_inputs(),
+        "all" => all(&args[2..], &config),
+        _ => run(&args[1..], &config),
+    }
+}
 
-    eprint!("Test P1 | ");
-    runner.parse(&test_data.input);
-    let test_answer = runner.run_p1();
-    if test_answer != test_data.answer_p1 {
-        eprintln!(
-            "Failed: expected {} - got answer {}",
-            test_data.answer_p1, test_answer
-        );
-        return;
+/// Removes a flag that can appear anywhere in the arguments
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|e| e == flag) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
     }
-    eprintln!("Success");
+}
 
-    eprint!("Part 1  | ");
-    runner.parse(&input);
-    let p1 = runner.run_p1();
-    eprintln!("{}", p1);
+/// Removes a flag and the value following it from anywhere in the arguments
+fn take_value(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let i = args.iter().position(|e| e == flag)?;
+    if i + 1 >= args.len() {
+        panic!("expected a value after {}", flag);
+    }
+    args.remove(i);
+    Some(args.remove(i))
+}
+
+fn parse_day(arg: Option<&String>) -> usize {
+    match arg.map(|e| e.parse::<usize>()) {
+        Some(Ok(day)) => day,
+        _ => panic!("expected day as a number"),
+    }
+}
+
+fn parse_flag<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> T {
+    match value.map(|e| e.parse::<T>()) {
+        Some(Ok(value)) => value,
+        _ => panic!("expected a number after {}", flag),
+    }
+}
 
-    eprint!("Test P2 | ");
-    runner.parse(&test_data.input);
-    let test_answer = runner.run_p2();
-    if test_answer != test_data.answer_p2 {
+fn generate(args: &[String]) {
+    let day = parse_day(args.first());
+    let mut seed = None;
+    let mut size = None;
+
+    let mut flags = args.iter().skip(1);
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--seed" => seed = Some(parse_flag(flag, flags.next())),
+            "--size" => size = Some(parse_flag(flag, flags.next())),
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    });
+    if let Some((knob, default)) = aoc::generate::size_info(day) {
         eprintln!(
-            "Failed: expected {} - got answer {}",
-            test_data.answer_p2, test_answer
+            "day {} | seed {} | size {} ({})",
+            day,
+            seed,
+            size.unwrap_or(default),
+            knob
         );
-        return;
     }
-    eprintln!("Success");
 
-    eprint!("Part 2  | ");
-    runner.parse(&input);
-    let p2 = runner.run_p2();
-    eprintln!("{}", p2);
+    match aoc::generate::generate(day, seed, size) {
+        Ok(lines) => println!("{}", lines.join("\n")),
+        Err(e) => panic!("{}", e),
+    }
 }
 
-fn read_file(path: String) -> Result<Vec<String>, String> {
-    let pb = std::path::PathBuf::from(&path);
-    if !pb.exists() {
-        return Err(format!("Couldnt find file at path: {}", path));
+fn diff(args: &[String]) {
+    let day = parse_day(args.first());
+    let mut seeds = 20;
+    let mut max_size = 10;
+
+    let mut flags = args.iter().skip(1);
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--seeds" => seeds = parse_flag(flag, flags.next()),
+            "--max-size" => max_size = parse_flag(flag, flags.next()),
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+
+    for part in 1..=2 {
+        eprint!("Diff P{} | ", part);
+        match aoc::differential::check(day, part, seeds, max_size) {
+            Ok(None) => eprintln!("Success"),
+            Ok(Some(disagreement)) => eprintln!("Failed: {}", disagreement),
+            Err(e) => eprintln!("Skipped: {}", e),
+        }
     }
-    let content = std::fs::read_to_string(pb).unwrap();
-    Ok(content.lines().map(|e| e.to_string()).collect())
 }
 
-fn get_input(day: usize) -> Vec<String> {
-    let path = format!("./resources/day{}.txt", day);
-    let content = read_file(path);
+fn serve(args: &[String], config: &Config) {
+    let mut port: u16 = 8080;
+    let mut timeout = config.timeout;
 
-    match content {
-        Ok(content) => content,
-        Err(_) => panic!("Couldnt find puzzle input for day {}", day),
+    let mut flags = args.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--port" => port = parse_flag(flag, flags.next()),
+            "--timeout" => timeout = parse_flag(flag, flags.next()),
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+
+    let budget = std::time::Duration::from_secs(timeout);
+    if let Err(e) = server::serve(&format!("127.0.0.1:{}", port), budget) {
+        panic!("{}", e);
+    }
+}
+
+/// Parses the input and runs a part within the budget, a parse error comes
+/// back as the rendered diagnostic.
+fn run_part(
+    runner: &mut Box<dyn Runner>,
+    input: &Vec<String>,
+    part: usize,
+    budget: std::time::Duration,
+) -> Result<usize, String> {
+    with_budget(budget, || {
+        runner
+            .parse(input)
+            .map_err(|e| format!("\n{}", e.render().trim_end()))?;
+        if part == 1 {
+            Ok(runner.run_p1())
+        } else {
+            Ok(runner.run_p2())
+        }
+    })
+    .map_err(|e| e.to_string())?
+}
+
+/// Encrypts the plain text inputs of every year, creating a key file when no
+/// key is set.
+fn encrypt_inputs() {
+    let key = match aoc::crypt::find_key() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            let hex = aoc::crypt::generate_key();
+            if let Err(e) = std::fs::write(aoc::crypt::KEY_FILE, &hex) {
+                panic!("Couldnt write {}: {}", aoc::crypt::KEY_FILE, e);
+            }
+            eprintln!(
+                "Wrote a new key to {}, the inputs cant be read without it",
+                aoc::crypt::KEY_FILE
+            );
+            aoc::crypt::parse_key(&hex, aoc::crypt::KEY_FILE).unwrap()
+        }
+        Err(e) => panic!("{}", e),
+    };
+
+    for year in aoc::runner::YEARS {
+        match aoc::input::encrypt_inputs(year, &key) {
+            Ok(paths) => {
+                for path in paths {
+                    eprintln!("Encrypted {}", path.display());
+                }
+            }
+            Err(e) => panic!("{}", e),
+        }
     }
 }
 
-struct TestData {
-    input: Vec<String>,
-    answer_p1: usize,
-    answer_p2: usize,
+/// Prints every day of the year with the status of both parts
+fn list(year: usize) {
+    for day in aoc::available_days_in(year) {
+        let [p1, p2] = match aoc::status(year, day) {
+            Ok(status) => status,
+            Err(e) => panic!("{}", e),
+        };
+        println!(
+            "Day {:>2}  {}{}  {:<13} {}",
+            day,
+            p1.symbol(),
+            p2.symbol(),
+            p1,
+            p2
+        );
+    }
+    println!("\n* solved  + example only  ~ partial  . unimplemented");
+
+    let solvers: Vec<_> = aoc::plugin::solvers()
+        .into_iter()
+        .filter(|e| e.year == year)
+        .collect();
+    if !solvers.is_empty() {
+        println!("\nPlugin solvers:");
+    }
+    for solver in solvers {
+        println!(
+            "Day {:>2}  {:<13} {}",
+            solver.day,
+            solver.name,
+            solver.path.display()
+        );
+    }
 }
-impl TestData {
-    pub fn new(input: Vec<String>, answer: (usize, usize)) -> Self {
-        Self {
-            input,
-            answer_p1: answer.0,
-            answer_p2: answer.1,
+
+/// Runs both parts of every day on the default profile and prints a table
+fn all(args: &[String], config: &Config) {
+    let year = config.year;
+    let mut timeout = config.timeout;
+
+    let mut flags = args.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--timeout" => timeout = parse_flag(flag, flags.next()),
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+
+    let cache = cache::Cache::new(config.cache.clone());
+    let budget = std::time::Duration::from_secs(timeout);
+    let mut errors = Vec::new();
+    let mut rows = Vec::new();
+
+    if config.output == Output::Text {
+        println!("Day | {:<20} | {:<20} | Time", "Part 1", "Part 2");
+    }
+    for day in aoc::available_days_in(year) {
+        let _span = tracing::info_span!("day", year, day).entered();
+        let mut runner = get_runner(year, day);
+        let input = aoc::input::get_input(year, &config.profile, day);
+        let now = std::time::Instant::now();
+
+        let mut cells = Vec::new();
+        for part in 1..=2 {
+            let status = runner.status(part);
+            let input = match (&input, status) {
+                (_, PartStatus::Unimplemented | PartStatus::ExampleOnly) => {
+                    cells.push(status.to_string());
+                    continue;
+                }
+                (Err(_), _) => {
+                    cells.push(String::from("no input"));
+                    continue;
+                }
+                (Ok(input), _) => input,
+            };
+
+            let result = if status == PartStatus::Partial {
+                run_part(&mut runner, input, part, budget).map(|answer| (answer, false))
+            } else {
+                cache.get_or_run(year, day, part, input, || {
+                    run_part(&mut runner, input, part, budget)
+                })
+            };
+            match result {
+                Ok((answer, _)) if status == PartStatus::Partial => {
+                    cells.push(format!("{} ({})", answer, status))
+                }
+                Ok((answer, _)) => cells.push(answer.to_string()),
+                Err(e) => {
+                    cells.push(String::from("failed"));
+                    errors.push(format!("Day {} part {}: {}", day, part, e));
+                }
+            }
+        }
+
+        match config.output {
+            Output::Text => println!(
+                "{:>3} | {:<20} | {:<20} | {:?}",
+                day,
+                cells[0],
+                cells[1],
+                now.elapsed()
+            ),
+            Output::Json => rows.push(json!({
+                "day": day,
+                "part1": cells[0],
+                "part2": cells[1],
+                "elapsed_us": now.elapsed().as_micros() as u64,
+            })),
         }
     }
+
+    if config.output == Output::Json {
+        println!("{}", json!({ "year": year, "days": rows }));
+    }
+    for e in errors {
+        eprintln!("{}", e);
+    }
 }
 
-fn get_test_data(day: usize) -> TestData {
-    let path = format!("./resources/day{}_test.txt", day);
-    let lines = match read_file(path) {
-        Err(_) => panic!("Couldnt find test input for day {}", day),
-        Ok(lines) => lines,
+fn run(args: &[String], config: &Config) {
+    let year = config.year;
+    let day = match args[0].parse::<usize>() {
+        Ok(day) => day,
+        Err(_) => panic!("expected first argument to be a number"),
     };
+    let mut profiles = vec![config.profile.clone()];
+    let mut record = false;
+    let mut timeout = config.timeout;
+    let mut examples = config.examples;
+    let mut watch = false;
+    let mut solver = Solver::Builtin;
 
-    let answer: (usize, usize) = {
-        let (p1, p2) = lines[0].split_once(" ").unwrap();
+    let mut flags = args.iter().skip(1);
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--profile" => match flags.next() {
+                Some(profile) => profiles = vec![profile.clone()],
+                None => panic!("expected a profile name after {}", flag),
+            },
+            "--all-profiles" => profiles = aoc::input::profiles(year, day),
+            "--record" => record = true,
+            "--timeout" => timeout = parse_flag(flag, flags.next()),
+            "--skip-examples" => examples = false,
+            "--watch" => watch = true,
+            "--solver" => match flags.next() {
+                Some(name) => solver = Solver::Plugin(name.clone()),
+                None => panic!("expected a solver name after {}", flag),
+            },
+            "--script" => solver = Solver::Script(config.scripts.join(format!("day{}.rhai", day))),
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+    if profiles.is_empty() {
+        panic!("no inputs for {} day {}", year, day);
+    }
+    if watch {
+        return watch::watch(watch::Options {
+            year,
+            day,
+            profiles,
+            solver,
+            budget: std::time::Duration::from_secs(timeout),
+            examples,
+        });
+    }
 
-        (p1.parse::<usize>().unwrap(), p2.parse::<usize>().unwrap())
-    };
+    let _span = tracing::info_span!("day", year, day).entered();
+    let mut runner = get_solver(year, day, &solver);
+    let inputs: Vec<Vec<String>> = profiles.iter().map(|p| get_input(year, p, day)).collect();
+    let recorded: Vec<Option<(usize, usize)>> = profiles
+        .iter()
+        .map(|p| match aoc::input::get_answers(year, p, day) {
+            Ok(answers) => answers,
+            Err(e) => panic!("{}", e),
+        })
+        .collect();
+    let test_data = examples.then(|| get_test_data(year, day));
+    // cached answers are keyed by the built in solver's source
+    let cache = cache::Cache::new(config.cache.clone().filter(|_| solver == Solver::Builtin));
+    let budget = std::time::Duration::from_secs(timeout);
+    let mut answers = vec![(0, 0); profiles.len()];
+    let mut results = Vec::new();
+    let mut failed = false;
+
+    for part in 1..=2 {
+        let status = runner.status(part);
+        if status == PartStatus::Unimplemented {
+            eprintln!("Part {}  | {}", part, status);
+            results.push(json!({ "part": part, "status": status.to_string() }));
+            failed = true;
+            continue;
+        }
+
+        if let Some(test_data) = &test_data {
+            let expected = if part == 1 {
+                test_data.answer_p1
+            } else {
+                test_data.answer_p2
+            };
+            eprint!("Test P{} | ", part);
+            match run_part(&mut runner, &test_data.input, part, budget) {
+                Ok(answer) if answer == expected => eprintln!("Success"),
+                Ok(answer) if status == PartStatus::Partial => {
+                    eprintln!("Partial: expected {} - got answer {}", expected, answer)
+                }
+                Ok(answer) => {
+                    eprintln!("Failed: expected {} - got answer {}", expected, answer);
+                    results.push(json!({ "part": part, "example": answer, "expected": expected }));
+                    failed = true;
+                    break;
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    results.push(json!({ "part": part, "example": null, "error": e }));
+                    failed = true;
+                    break;
+                }
+            }
+        }
 
-    let input: Vec<String> = lines.iter().skip(2).map(|e| e.to_string()).collect();
+        if status == PartStatus::ExampleOnly {
+            eprintln!("Part {}  | {}", part, status);
+            results.push(json!({ "part": part, "status": status.to_string() }));
+            failed = true;
+            continue;
+        }
+
+        for (i, profile) in profiles.iter().enumerate() {
+            eprint!("{}", part_label(part, profile));
+            // partial answers are not cached, showing the state needs a parse
+            let result = if status == PartStatus::Partial {
+                run_part(&mut runner, &inputs[i], part, budget).map(|answer| (answer, false))
+            } else {
+                cache.get_or_run(year, day, part, &inputs[i], || {
+                    run_part(&mut runner, &inputs[i], part, budget)
+                })
+            };
+            match &result {
+                Ok((answer, _)) if status == PartStatus::Partial => {
+                    eprintln!("{} ({})", answer, status);
+                    if let Some(shown) = runner.show() {
+                        eprintln!("{}", shown);
+                    }
+                    failed = true;
+                }
+                &Ok((answer, cached)) if part == 1 => {
+                    report_answer(answer, cached, recorded[i].map(|e| e.0));
+                    answers[i].0 = answer;
+                }
+                &Ok((answer, cached)) => {
+                    report_answer(answer, cached, recorded[i].map(|e| e.1));
+                    answers[i].1 = answer;
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    failed = true;
+                }
+            }
+            results.push(match result {
+                Ok((answer, cached)) => json!({
+                    "part": part,
+                    "profile": profile,
+                    "status": status.to_string(),
+                    "answer": answer,
+                    "cached": cached,
+                }),
+                Err(e) => json!({ "part": part, "profile": profile, "error": e }),
+            });
+        }
+    }
 
-    TestData::new(input, answer)
+    if config.output == Output::Json {
+        println!(
+            "{}",
+            json!({ "year": year, "day": day, "results": results })
+        );
+    }
+
+    if record && failed {
+        eprintln!("Not recording answers, some parts failed or are not solved");
+    } else if record {
+        for (profile, &answers) in profiles.iter().zip(&answers) {
+            if let Err(e) = aoc::input::record_answers(year, profile, day, answers) {
+                panic!("{}", e);
+            }
+            eprintln!("Recorded answers for profile {}", profile);
+        }
+    }
+}
+
+fn part_label(part: usize, profile: &str) -> String {
+    if profile == aoc::input::DEFAULT_PROFILE {
+        format!("Part {}  | ", part)
+    } else {
+        format!("Part {}  | {} | ", part, profile)
+    }
+}
+
+/// Prints an answer and how it compares to the one recorded for the profile
+fn report_answer(answer: usize, cached: bool, recorded: Option<usize>) {
+    let cached = if cached { " (cached)" } else { "" };
+    match recorded {
+        Some(expected) if expected != answer => {
+            eprintln!("{}{} - Failed: expected {}", answer, cached, expected)
+        }
+        Some(_) => eprintln!("{}{} - Correct", answer, cached),
+        None => eprintln!("{}{}", answer, cached),
+    }
+}
+
+fn get_input(year: usize, profile: &str, day: usize) -> Vec<String> {
+    match aoc::input::get_input(year, profile, day) {
+        Ok(content) => content,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn get_test_data(year: usize, day: usize) -> aoc::input::TestData {
+    match aoc::input::get_test_data(year, day) {
+        Ok(test_data) => test_data,
+        Err(e) => panic!("{}", e),
+    }
 }
 
 mod tests {
     #[test]
     fn parse_test_data_test() {
-        let test_data = super::get_test_data(1);
+        let test_data = super::get_test_data(2021, 1);
         assert_eq!(7, test_data.answer_p1);
         assert_eq!(5, test_data.answer_p2);
         assert_eq!(10, test_data.input.len());
     }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in 1..=22 {
+            let input = aoc::generate::generate(day, 1, Some(6)).unwrap();
+            let mut runner = super::get_runner(aoc::generate::YEAR, day);
+            runner.parse(&input).unwrap();
+
+            for part in 1..=2 {
+                match (part, runner.status(part)) {
+                    (_, super::PartStatus::Unimplemented) => (),
+                    (1, _) => {
+                        runner.run_p1();
+                    }
+                    _ => {
+                        runner.run_p2();
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,188 @@
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use aoc::cancel::{with_budget, DEFAULT_BUDGET};
+use aoc::runner::Runner;
+
+const HELP: &str = "\
+load <day> [path]  switch day, reads resources/<year>/day<day>.txt without a path
+reload             read the input file again and parse it
+parse              parse the loaded input
+p1 | p2            run a part, parses first if needed
+set <key> <value>  change a setting of the day, like `set steps 40`
+show               print the parsed state
+help               print this message
+quit";
+
+struct Session {
+    year: usize,
+    day: usize,
+    path: PathBuf,
+    input: Vec<String>,
+    runner: Box<dyn Runner>,
+    parsed: bool,
+}
+
+impl Session {
+    fn load(year: usize, day: usize, path: Option<PathBuf>) -> Result<Session, String> {
+        let runner = aoc::runner::get_runner(year, day)
+            .ok_or_else(|| format!("Runner for {} day {} not implemented", year, day))?;
+        let path =
+            path.unwrap_or_else(|| aoc::input::input_path(year, aoc::input::DEFAULT_PROFILE, day));
+        let input = aoc::input::read_input(&path)?;
+
+        Ok(Session {
+            year,
+            day,
+            path,
+            input,
+            runner,
+            parsed: false,
+        })
+    }
+
+    fn parse(&mut self) -> Result<String, String> {
+        let now = Instant::now();
+        let (runner, input) = (&mut self.runner, &self.input);
+        catch(|| runner.parse(input).map_err(|e| e.render()))??;
+        self.parsed = true;
+
+        Ok(format!(
+            "Parsed {} lines in {:?}",
+            self.input.len(),
+            now.elapsed()
+        ))
+    }
+
+    fn run(&mut self, part: usize) -> Result<String, String> {
+        if !self.parsed {
+            self.parse()?;
+        }
+
+        let now = Instant::now();
+        let runner = &self.runner;
+        let answer = catch(|| {
+            with_budget(DEFAULT_BUDGET, || {
+                if part == 1 {
+                    runner.run_p1()
+                } else {
+                    runner.run_p2()
+                }
+            })
+        })?
+        .map_err(|e| e.to_string())?;
+
+        Ok(format!("Part {} | {} | {:?}", part, answer, now.elapsed()))
+    }
+
+    fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["load", day] | ["load", day, _] => {
+                let day = day
+                    .parse::<usize>()
+                    .map_err(|_| format!("Expected day as a number, got {}", day))?;
+                *self = Session::load(self.year, day, words.get(2).map(PathBuf::from))?;
+                Ok(format!(
+                    "Loaded day {} from {}",
+                    self.day,
+                    self.path.display()
+                ))
+            }
+            ["reload"] => {
+                self.input = aoc::input::read_input(&self.path)?;
+                self.parse()
+            }
+            ["parse"] => self.parse(),
+            ["p1"] => self.run(1),
+            ["p2"] => self.run(2),
+            ["set", key, value] => {
+                self.runner.set(key, value)?;
+                Ok(format!("{} = {}", key, value))
+            }
+            ["show"] => {
+                if !self.parsed {
+                    return Err(String::from("Nothing parsed yet"));
+                }
+                self.runner
+                    .show()
+                    .ok_or_else(|| format!("Day {} has nothing to show", self.day))
+            }
+            ["help"] => Ok(String::from(HELP)),
+            [] => Ok(String::new()),
+            _ => Err(format!("Unknown command {}, try help", line.trim())),
+        }
+    }
+}
+
+/// Turns a panic in the solver into an error so the session survives it
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(msg) = payload.downcast_ref::<&str>() {
+            format!("Panicked: {}", msg)
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            format!("Panicked: {}", msg)
+        } else {
+            String::from("Panicked")
+        }
+    })
+}
+
+pub fn repl(year: usize, day: usize, path: Option<String>) {
+    let mut session = match Session::load(year, day, path.map(PathBuf::from)) {
+        Ok(session) => session,
+        Err(e) => panic!("{}", e),
+    };
+    eprintln!(
+        "Loaded day {} from {}, type help for commands",
+        day,
+        session.path.display()
+    );
+
+    let stdin = std::io::stdin();
+    loop {
+        print!("day{}> ", session.day);
+        std::io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+
+        match session.execute(&line) {
+            Ok(out) if out.is_empty() => (),
+            Ok(out) => println!("{}", out),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn session_keeps_state() {
+        let path = std::env::temp_dir().join(format!("aoc_repl_test_{}.txt", std::process::id()));
+        std::fs::write(&path, "3,4,3,1,2\n").unwrap();
+
+        let mut session = super::Session::load(2021, 6, Some(path.clone())).unwrap();
+        assert!(session.execute("show").is_err());
+        assert!(session.execute("p1").unwrap().contains("| 5934 |"));
+
+        session.execute("set steps 18").unwrap();
+        assert!(session.execute("p1").unwrap().contains("| 26 |"));
+        assert!(session.execute("set steps many").is_err());
+        assert!(session.execute("set speed 1").is_err());
+
+        std::fs::write(&path, "3\n").unwrap();
+        session.execute("reload").unwrap();
+        assert!(session.execute("p2").unwrap().contains("| 5 |"));
+        assert!(session.execute("bogus").is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+//! Solutions written as Rhai scripts, for trying an idea without adding a
+//! module to the `aoc` crate.
+//!
+//! A script defines `parse(input)`, getting the lines as an array of strings
+//! and returning whatever the parts need, and `p1(parsed)` and `p2(parsed)`
+//! returning the answers. A script without `p2` has that part unimplemented.
+//! The helpers below are registered for parsing and walking grids, their
+//! errors point at the input like the ones of compiled days.
+
+use std::path::{Path, PathBuf};
+
+use aoc::diagnostic::{self, ParseError};
+use aoc::runner::{PartStatus, Runner};
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Position, Scope, AST, INT};
+
+pub struct ScriptRunner {
+    year: usize,
+    day: usize,
+    path: PathBuf,
+    engine: Engine,
+    ast: AST,
+    parsed: Dynamic,
+}
+
+impl ScriptRunner {
+    pub fn load(year: usize, day: usize, path: &Path) -> Result<ScriptRunner, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Couldnt read script {}: {}", path.display(), e))?;
+        ScriptRunner::compile(year, day, path, &source)
+    }
+
+    pub fn compile(
+        year: usize,
+        day: usize,
+        path: &Path,
+        source: &str,
+    ) -> Result<ScriptRunner, String> {
+        let engine = engine();
+        let ast = engine
+            .compile(source)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        if !has_fn(&ast, "parse") || !has_fn(&ast, "p1") {
+            return Err(format!(
+                "{} needs a parse(input) and a p1(parsed) function",
+                path.display()
+            ));
+        }
+
+        Ok(ScriptRunner {
+            year,
+            day,
+            path: path.to_path_buf(),
+            engine,
+            ast,
+            parsed: Dynamic::UNIT,
+        })
+    }
+
+    fn call(&self, name: &str) -> usize {
+        let answer =
+            self.engine
+                .call_fn::<INT>(&mut Scope::new(), &self.ast, name, (self.parsed.clone(),));
+        match answer {
+            Ok(answer) => answer as usize,
+            Err(e) => panic!("{}: {}", self.path.display(), e),
+        }
+    }
+}
+
+impl Runner for ScriptRunner {
+    fn parse(&mut self, input: &Vec<String>) -> Result<(), ParseError> {
+        let lines: Array = input.iter().map(|e| Dynamic::from(e.clone())).collect();
+        let parsed =
+            self.engine
+                .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, "parse", (lines,));
+
+        self.parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => match parse_error(&e) {
+                Some(mut error) => {
+                    error.context.day = Some((self.year, self.day));
+                    error.context.expected = aoc::runner::input_format(self.year, self.day);
+                    return Err(error);
+                }
+                None => panic!("{}: {}", self.path.display(), e),
+            },
+        };
+        Ok(())
+    }
+
+    fn run_p1(&self) -> usize {
+        self.call("p1")
+    }
+
+    fn run_p2(&self) -> usize {
+        self.call("p2")
+    }
+
+    fn status(&self, part: usize) -> PartStatus {
+        if part == 2 && !has_fn(&self.ast, "p2") {
+            PartStatus::Unimplemented
+        } else {
+            PartStatus::Solved
+        }
+    }
+}
+
+fn has_fn(ast: &AST, name: &str) -> bool {
+    ast.iter_functions()
+        .any(|f| f.name == name && f.params.len() == 1)
+}
+
+/// The `ParseError` thrown by a helper, through the script functions it was
+/// called from.
+fn parse_error(e: &EvalAltResult) -> Option<ParseError> {
+    match e {
+        EvalAltResult::ErrorRuntime(value, _) => value.clone().try_cast::<ParseError>(),
+        EvalAltResult::ErrorInFunctionCall(_, _, inner, _) => parse_error(inner),
+        _ => None,
+    }
+}
+
+/// Boxes the error where a helper fails, scripts only ever see it boxed
+fn throw(e: ParseError) -> Box<EvalAltResult> {
+    Box::new(EvalAltResult::ErrorRuntime(
+        Dynamic::from(e),
+        Position::NONE,
+    ))
+}
+
+fn strings(input: Array) -> Result<Vec<String>, Box<EvalAltResult>> {
+    input
+        .into_iter()
+        .map(|e| {
+            e.into_string()
+                .map_err(|t| format!("expected lines, found {}", t).into())
+        })
+        .collect()
+}
+
+/// One number per line
+fn numbers(input: Array) -> Result<Array, Box<EvalAltResult>> {
+    let input = strings(input)?;
+    diagnostic::lines(&input)
+        .map(|l| l.number::<INT>(l.text).map(Dynamic::from).map_err(throw))
+        .collect()
+}
+
+/// Every line split on `sep` into numbers
+fn split_numbers(input: Array, sep: &str) -> Result<Array, Box<EvalAltResult>> {
+    let input = strings(input)?;
+    diagnostic::lines(&input)
+        .map(|l| {
+            l.text
+                .split(sep)
+                .map(|part| l.number::<INT>(part).map(Dynamic::from).map_err(throw))
+                .collect::<Result<Array, _>>()
+                .map(Dynamic::from_array)
+        })
+        .collect()
+}
+
+/// Rows of single digits, indexed as `grid[y][x]`
+fn digit_grid(input: Array) -> Result<Array, Box<EvalAltResult>> {
+    let input = strings(input)?;
+    let grid = diagnostic::digit_grid(&input).map_err(throw)?;
+    Ok(grid
+        .into_iter()
+        .map(|row| {
+            let row: Array = row.into_iter().map(|e| Dynamic::from(e as INT)).collect();
+            Dynamic::from_array(row)
+        })
+        .collect())
+}
+
+/// Positions `[x, y]` next to `x, y` that are inside the grid, diagonals too
+/// when `diagonal` is set.
+fn neighbours(grid: Array, x: INT, y: INT, diagonal: bool) -> Array {
+    let height = grid.len() as INT;
+    let width = grid.first().map_or(0, |row| {
+        row.read_lock::<Array>().map_or(0, |row| row.len()) as INT
+    });
+
+    let mut out = Array::new();
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if (dx, dy) == (0, 0) || (!diagonal && dx != 0 && dy != 0) {
+                continue;
+            }
+            let (nx, ny) = (x + dx, y + dy);
+            if nx >= 0 && ny >= 0 && nx < width && ny < height {
+                out.push(Dynamic::from_array(vec![
+                    Dynamic::from(nx),
+                    Dynamic::from(ny),
+                ]));
+            }
+        }
+    }
+    out
+}
+
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    // lets a time budget stop a script stuck in a loop
+    engine.on_progress(|_| {
+        aoc::cancel::checkpoint();
+        None
+    });
+
+    engine
+        .register_fn("numbers", numbers)
+        .register_fn("split_numbers", split_numbers)
+        .register_fn("digit_grid", digit_grid)
+        .register_fn("neighbours", |grid: Array, x: INT, y: INT| {
+            neighbours(grid, x, y, false)
+        })
+        .register_fn("neighbours8", |grid: Array, x: INT, y: INT| {
+            neighbours(grid, x, y, true)
+        });
+    engine
+}
+
+mod tests {
+    #[test]
+    fn script_solves_example() {
+        use super::*;
+
+        let source = "
+            fn parse(input) { digit_grid(input) }
+            fn p1(grid) {
+                let low = 0;
+                for y in 0..grid.len() {
+                    for x in 0..grid[y].len() {
+                        let lowest = true;
+                        for n in neighbours(grid, x, y) {
+                            if grid[n[1]][n[0]] <= grid[y][x] { lowest = false; }
+                        }
+                        if lowest { low += grid[y][x] + 1; }
+                    }
+                }
+                low
+            }
+        ";
+        let input: Vec<String> = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678"
+            .lines()
+            .map(String::from)
+            .collect();
+
+        let mut runner = ScriptRunner::compile(2021, 9, Path::new("day9.rhai"), source).unwrap();
+        runner.parse(&input).unwrap();
+        assert_eq!(15, runner.run_p1());
+        assert_eq!(PartStatus::Unimplemented, runner.status(2));
+
+        let error = runner
+            .parse(&vec![String::from("21"), String::from("3x")])
+            .unwrap_err();
+        assert_eq!((1, 1), (error.context.line, error.context.column));
+        assert_eq!(Some((2021, 9)), error.context.day);
+
+        assert!(ScriptRunner::compile(2021, 9, Path::new("day9.rhai"), "fn p1(x) { 1 }").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Serves the solvers on `addr` until the process is killed, every part gets
+/// `budget` to finish.
+///
+/// `GET /{year}/days` lists the available days and the status of their parts,
+/// `POST /{year}/day/{n}/part/{p}` solves the request body as puzzle input.
+pub fn serve(addr: &str, budget: Duration) -> Result<(), String> {
+    let server = Server::http(addr).map_err(|e| format!("Couldnt listen on {}: {}", addr, e))?;
+    eprintln!("Listening on http://{}", server.server_addr());
+
+    run(&server, budget);
+    Ok(())
+}
+
+fn run(server: &Server, budget: Duration) {
+    for request in server.incoming_requests() {
+        respond(request, budget);
+    }
+}
+
+fn respond(mut request: Request, budget: Duration) {
+    let mut body = String::new();
+    let (status, json) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => handle(request.method(), request.url(), &body, budget),
+        Err(_) => (400, json!({ "error": "Body is not valid UTF-8" })),
+    };
+
+    let header = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(header);
+
+    if let Err(e) = request.respond(response) {
+        eprintln!("Couldnt send response: {}", e);
+    }
+}
+
+fn handle(method: &Method, url: &str, body: &str, budget: Duration) -> (u16, Value) {
+    let mut segments = url.trim_matches('/').split('/');
+    let year = match segments.next().unwrap().parse::<usize>() {
+        Ok(year) if aoc::runner::YEARS.contains(&year) => year,
+        Ok(year) => {
+            return (
+                404,
+                json!({ "error": aoc::Error::UnknownYear(year).to_string() }),
+            )
+        }
+        Err(_) => return (404, json!({ "error": format!("No route for {}", url) })),
+    };
+    let segments: Vec<&str> = segments.collect();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => {
+            let days = aoc::available_days_in(year);
+            let status: serde_json::Map<String, Value> = days
+                .iter()
+                .filter_map(|&day| {
+                    let status = aoc::status(year, day).ok()?;
+                    Some((day.to_string(), json!(status.map(|e| e.to_string()))))
+                })
+                .collect();
+            (200, json!({ "days": days, "status": status }))
+        }
+        (Method::Post, ["day", day, "part", part]) => {
+            let (day, part) = match (day.parse::<usize>(), part.parse::<usize>()) {
+                (Ok(day), Ok(part)) => (day, part),
+                _ => return (400, json!({ "error": "Day and part must be numbers" })),
+            };
+
+            let now = Instant::now();
+            match aoc::solve_within(year, day, part, body, budget) {
+                Ok(answer) => (
+                    200,
+                    json!({
+                        "year": year,
+                        "day": day,
+                        "part": part,
+                        "answer": answer.0,
+                        "elapsed_us": now.elapsed().as_micros() as u64,
+                    }),
+                ),
+                Err(e @ aoc::Error::UnknownDay(_)) => (404, json!({ "error": e.to_string() })),
+                Err(e @ aoc::Error::TimedOut(_)) => (503, json!({ "error": e.to_string() })),
+                Err(e) => (400, json!({ "error": e.to_string() })),
+            }
+        }
+        (_, ["days"]) | (_, ["day", _, "part", _]) => {
+            (405, json!({ "error": "Method not allowed" }))
+        }
+        _ => (404, json!({ "error": format!("No route for {}", url) })),
+    }
+}
+
+mod tests {
+    #[test]
+    fn serve_in_process() {
+        use std::io::{Read, Write};
+        use std::net::TcpStream;
+        use std::time::Duration;
+
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_string();
+        std::thread::spawn(move || super::run(&server, Duration::from_secs(60)));
+
+        let request = |method: &str, path: &str, body: &str| {
+            let mut stream = TcpStream::connect(&addr).unwrap();
+            write!(
+                stream,
+                "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+                method,
+                path,
+                addr,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+
+            let status = response[9..12].parse::<u16>().unwrap();
+            let (_, body) = response.split_once("\r\n\r\n").unwrap();
+            (
+                status,
+                serde_json::from_str::<serde_json::Value>(body).unwrap(),
+            )
+        };
+
+        let (status, json) = request("GET", "/2021/days", "");
+        assert_eq!(200, status);
+        assert_eq!(22, json["days"].as_array().unwrap().len());
+        assert_eq!(
+            serde_json::json!(["solved", "partial"]),
+            json["status"]["13"]
+        );
+
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let (status, json) = request("POST", "/2021/day/1/part/1", input);
+        assert_eq!(200, status);
+        assert_eq!(7, json["answer"]);
+        assert!(json["elapsed_us"].is_u64());
+
+        let (status, _) = request("POST", "/2021/day/30/part/1", input);
+        assert_eq!(404, status);
+
+        let (status, _) = request("POST", "/1999/day/1/part/1", input);
+        assert_eq!(404, status);
+
+        let (status, _) = request("POST", "/day/1/part/1", input);
+        assert_eq!(404, status);
+
+        let (status, _) = request("GET", "/2021/day/1/part/1", "");
+        assert_eq!(405, status);
+
+        // solving takes longer than no time at all
+        let post = tiny_http::Method::Post;
+        let (status, _) = super::handle(&post, "/2021/day/1/part/1", input, Duration::ZERO);
+        assert_eq!(503, status);
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+#[derive(Default)]
+pub struct TraceOptions {
+    /// print a tree of span timings to stderr
+    pub timings: bool,
+    /// write a Chrome trace (chrome://tracing, Perfetto) to this path
+    pub chrome: Option<String>,
+    /// record the trace events inside the solvers as well
+    pub verbose: bool,
+}
+
+/// Installs the subscriber, the returned guard flushes the Chrome trace when dropped.
+pub fn init(options: &TraceOptions) -> Option<tracing_chrome::FlushGuard> {
+    if !options.timings && options.chrome.is_none() && !options.verbose {
+        return None;
+    }
+
+    let level = if options.verbose {
+        LevelFilter::TRACE
+    } else {
+        LevelFilter::INFO
+    };
+
+    let (chrome, guard) = match &options.chrome {
+        Some(path) => {
+            let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+                .file(path)
+                .include_args(true)
+                .build();
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+    let events = options.verbose.then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .without_time()
+    });
+
+    tracing_subscriber::registry()
+        .with(level)
+        .with(options.timings.then_some(TimingTree))
+        .with(chrome)
+        .with(events)
+        .init();
+
+    guard
+}
+
+/// Prints how long each span was entered for, nested under its parent, once
+/// the outermost span closes. With the `alloc-stats` feature the allocations
+/// made inside each span are printed next to the timing.
+pub struct TimingTree;
+
+struct Node {
+    label: String,
+    busy: Duration,
+    entered: Option<Instant>,
+    children: Vec<Node>,
+    #[cfg(feature = "alloc-stats")]
+    alloc_start: Option<crate::alloc::Snapshot>,
+    #[cfg(feature = "alloc-stats")]
+    alloc: crate::alloc::Stats,
+}
+
+impl Node {
+    fn print(&self, depth: usize) {
+        #[cfg(not(feature = "alloc-stats"))]
+        eprintln!("{}{} | {:?}", "  ".repeat(depth), self.label, self.busy);
+        #[cfg(feature = "alloc-stats")]
+        eprintln!(
+            "{}{} | {:?} | {}",
+            "  ".repeat(depth),
+            self.label,
+            self.busy,
+            self.alloc
+        );
+        for child in &self.children {
+            child.print(depth + 1);
+        }
+    }
+}
+
+struct LabelVisitor<'a>(&'a mut String);
+
+impl Visit for LabelVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        write!(self.0, " {}={:?}", field.name(), value).unwrap();
+    }
+}
+
+impl<S> Layer<S> for TimingTree
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut label = span.name().to_string();
+        attrs.record(&mut LabelVisitor(&mut label));
+
+        span.extensions_mut().insert(Node {
+            label,
+            busy: Duration::ZERO,
+            entered: None,
+            children: Vec::new(),
+            #[cfg(feature = "alloc-stats")]
+            alloc_start: None,
+            #[cfg(feature = "alloc-stats")]
+            alloc: Default::default(),
+        });
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        if let Some(node) = extensions.get_mut::<Node>() {
+            node.entered = Some(Instant::now());
+            #[cfg(feature = "alloc-stats")]
+            {
+                node.alloc_start = Some(crate::alloc::start_phase());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        if let Some(node) = extensions.get_mut::<Node>() {
+            if let Some(entered) = node.entered.take() {
+                node.busy += entered.elapsed();
+            }
+            #[cfg(feature = "alloc-stats")]
+            if let Some(start) = node.alloc_start.take() {
+                node.alloc.merge(crate::alloc::end_phase(start));
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let node = match span.extensions_mut().remove::<Node>() {
+            Some(node) => node,
+            None => return,
+        };
+
+        match span.parent() {
+            Some(parent) => {
+                let mut extensions = parent.extensions_mut();
+                if let Some(parent) = extensions.get_mut::<Node>() {
+                    parent.children.push(node);
+                }
+            }
+            None => node.print(0),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! Full screen dashboard with the calendar of a year. Enter runs the selected
+//! day on its example and input and shows the answers, timings and the state
+//! of days that can draw it.
+
+use std::time::{Duration, Instant};
+
+use aoc::runner::PartStatus;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{Frame, Terminal};
+
+/// Days in a calendar, shown as a grid of `COLUMNS` per row
+const DAYS: usize = 25;
+const COLUMNS: usize = 5;
+
+/// Answer of a part on the example or the input, and how long it took
+#[derive(Debug, Clone, PartialEq)]
+struct Outcome {
+    answer: Result<usize, String>,
+    elapsed: Duration,
+}
+
+struct Day {
+    day: usize,
+    /// False when there is no runner for the day
+    available: bool,
+    status: [PartStatus; 2],
+    expected: [Option<usize>; 2],
+    example: [Option<Outcome>; 2],
+    input: [Option<Outcome>; 2],
+    shown: Option<String>,
+}
+
+pub struct App {
+    year: usize,
+    days: Vec<Day>,
+    /// Index into `days`
+    selected: usize,
+    budget: Duration,
+    quit: bool,
+}
+
+impl App {
+    pub fn new(year: usize, budget: Duration) -> App {
+        let days = (1..=DAYS)
+            .map(|day| {
+                let runner = aoc::runner::get_runner(year, day);
+                Day {
+                    day,
+                    available: runner.is_some(),
+                    status: runner.map_or([PartStatus::Unimplemented; 2], |r| {
+                        [r.status(1), r.status(2)]
+                    }),
+                    expected: [None, None],
+                    example: [None, None],
+                    input: [None, None],
+                    shown: None,
+                }
+            })
+            .collect();
+
+        App {
+            year,
+            days,
+            selected: 0,
+            budget,
+            quit: false,
+        }
+    }
+
+    fn handle(&mut self, key: KeyCode) {
+        let selected = match key {
+            KeyCode::Left | KeyCode::Char('h') => self.selected.checked_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => Some(self.selected + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.checked_sub(COLUMNS),
+            KeyCode::Down | KeyCode::Char('j') => Some(self.selected + COLUMNS),
+            KeyCode::Enter => {
+                self.run_selected();
+                None
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.quit = true;
+                None
+            }
+            _ => None,
+        };
+        if let Some(selected) = selected.filter(|&e| e < DAYS) {
+            self.selected = selected;
+        }
+    }
+
+    /// Runs both parts on the example and the default input
+    fn run_selected(&mut self) {
+        let (year, budget) = (self.year, self.budget);
+        let day = &mut self.days[self.selected];
+        if !day.available {
+            return;
+        }
+
+        let mut runner = crate::get_runner(year, day.day);
+        let test_data = aoc::input::get_test_data(year, day.day);
+        let input = aoc::input::get_input(year, aoc::input::DEFAULT_PROFILE, day.day);
+        let mut run = |input: &Vec<String>, part| {
+            let now = Instant::now();
+            let answer = crate::run_part(&mut runner, input, part, budget);
+            Outcome {
+                answer,
+                elapsed: now.elapsed(),
+            }
+        };
+
+        for part in 1..=2 {
+            let i = part - 1;
+            if day.status[i] == PartStatus::Unimplemented {
+                continue;
+            }
+            day.example[i] = Some(match &test_data {
+                Ok(test_data) => {
+                    day.expected[i] = Some([test_data.answer_p1, test_data.answer_p2][i]);
+                    run(&test_data.input, part)
+                }
+                Err(e) => Outcome {
+                    answer: Err(e.clone()),
+                    elapsed: Duration::ZERO,
+                },
+            });
+            if day.status[i] == PartStatus::ExampleOnly {
+                continue;
+            }
+            day.input[i] = Some(match &input {
+                Ok(input) => run(input, part),
+                Err(e) => Outcome {
+                    answer: Err(e.clone()),
+                    elapsed: Duration::ZERO,
+                },
+            });
+        }
+        day.shown = runner.show();
+    }
+}
+
+fn outcome(outcome: &Option<Outcome>, expected: Option<usize>) -> String {
+    match outcome {
+        None => String::from("-"),
+        Some(Outcome {
+            answer: Ok(answer),
+            elapsed,
+        }) => match expected {
+            Some(expected) if expected != *answer => {
+                format!("{} expected {} ({:?})", answer, expected, elapsed)
+            }
+            _ => format!("{} ({:?})", answer, elapsed),
+        },
+        Some(Outcome { answer: Err(e), .. }) => {
+            format!("error: {}", e.trim().lines().next().unwrap_or(""))
+        }
+    }
+}
+
+fn calendar(app: &App) -> Vec<Line<'static>> {
+    app.days
+        .chunks(COLUMNS)
+        .map(|row| {
+            let spans: Vec<Span> = row
+                .iter()
+                .map(|day| {
+                    let text = format!(
+                        " {:>2} {}{} ",
+                        day.day,
+                        day.status[0].symbol(),
+                        day.status[1].symbol()
+                    );
+                    let mut style = Style::new();
+                    if !day.available {
+                        style = style.fg(Color::DarkGray);
+                    }
+                    if day.day == app.days[app.selected].day {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    Span::styled(text, style)
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn details(day: &Day) -> Vec<Line<'static>> {
+    if !day.available {
+        return vec![Line::from(format!("Day {} has no solver", day.day))];
+    }
+
+    let mut lines = vec![Line::from(format!("Day {}", day.day)), Line::from("")];
+    for i in 0..2 {
+        lines.push(Line::from(format!(
+            "Part {}  {:<13} {}",
+            i + 1,
+            day.status[i],
+            outcome(&day.input[i], None)
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from("Example"));
+    for i in 0..2 {
+        lines.push(Line::from(format!(
+            "Part {}  {}",
+            i + 1,
+            outcome(&day.example[i], day.expected[i])
+        )));
+    }
+    lines
+}
+
+fn draw(f: &mut Frame, app: &App) {
+    let [left, right] = Layout::horizontal([
+        Constraint::Length(COLUMNS as u16 * 7 + 2),
+        Constraint::Min(0),
+    ])
+    .areas(f.area());
+    let [calendar_area, help_area] = Layout::vertical([
+        Constraint::Length(DAYS.div_ceil(COLUMNS) as u16 + 2),
+        Constraint::Min(0),
+    ])
+    .areas(left);
+    let [details_area, shown_area] =
+        Layout::vertical([Constraint::Length(10), Constraint::Min(0)]).areas(right);
+
+    let day = &app.days[app.selected];
+    f.render_widget(
+        Paragraph::new(calendar(app)).block(Block::bordered().title(format!(" {} ", app.year))),
+        calendar_area,
+    );
+    f.render_widget(
+        Paragraph::new(vec![
+            Line::from("arrows  select"),
+            Line::from("enter   run"),
+            Line::from("q       quit"),
+        ]),
+        help_area,
+    );
+    f.render_widget(
+        Paragraph::new(details(day)).block(Block::bordered()),
+        details_area,
+    );
+    if let Some(shown) = &day.shown {
+        f.render_widget(
+            Paragraph::new(shown.clone()).block(Block::bordered().title(" State ")),
+            shown_area,
+        );
+    }
+}
+
+/// Draws and handles keys until quit
+fn event_loop<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> std::io::Result<()> {
+    while !app.quit {
+        terminal.draw(|f| draw(f, app))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.handle(key.code);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn tui(year: usize, budget: Duration) {
+    // diagnostics are shown as plain text
+    colored::control::set_override(false);
+
+    let mut app = App::new(year, budget);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+mod tests {
+    #[test]
+    fn dashboard_runs_selected_day() {
+        use super::*;
+        use ratatui::backend::TestBackend;
+
+        let mut app = App::new(2021, Duration::from_secs(10));
+        assert!(app.days.iter().take(17).all(|e| e.available));
+        assert!(!app.days[24].available);
+
+        // day 6 is below day 1
+        app.handle(KeyCode::Down);
+        app.handle(KeyCode::Up);
+        app.handle(KeyCode::Up);
+        app.handle(KeyCode::Down);
+        assert_eq!(6, app.days[app.selected].day);
+        app.handle(KeyCode::Enter);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal.draw(|f| draw(f, &app)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|e| e.symbol())
+            .collect();
+
+        assert!(screen.contains("Day 6"));
+        assert!(screen.contains(" 6 **"));
+        assert!(screen.contains("5934 ("));
+        assert!(screen.contains("26984457539 ("));
+
+        app.handle(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+}
@@ -0,0 +1,179 @@
+//! `--watch` keeps the process alive and solves the day again whenever its
+//! example or one of its inputs changes, printing what changed since the
+//! previous run.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc::runner::PartStatus;
+
+/// How often the files are checked for changes
+const POLL: Duration = Duration::from_millis(300);
+
+pub struct Options {
+    pub year: usize,
+    pub day: usize,
+    pub profiles: Vec<String>,
+    pub solver: crate::Solver,
+    pub budget: Duration,
+    pub examples: bool,
+}
+
+/// The result of the example or a profile for one part
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    label: String,
+    answer: Result<usize, String>,
+    elapsed: Duration,
+}
+
+/// Files that trigger a new run, the encrypted version of an input included
+fn watched_files(options: &Options) -> Vec<PathBuf> {
+    let mut files = vec![aoc::input::test_path(options.year, options.day)];
+    for profile in &options.profiles {
+        let path = aoc::input::input_path(options.year, profile, options.day);
+        files.push(aoc::input::encrypted_path(&path));
+        files.push(path);
+    }
+    files
+}
+
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|e| std::fs::metadata(e).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn solve(options: &Options) -> Vec<Entry> {
+    let mut runner = crate::get_solver(options.year, options.day, &options.solver);
+    let test_data = aoc::input::get_test_data(options.year, options.day);
+    let mut entries = Vec::new();
+
+    for part in 1..=2 {
+        let status = runner.status(part);
+        if status == PartStatus::Unimplemented {
+            continue;
+        }
+
+        if options.examples {
+            let now = Instant::now();
+            let answer = test_data
+                .as_ref()
+                .map_err(|e| e.clone())
+                .and_then(|test_data| {
+                    let expected = if part == 1 {
+                        test_data.answer_p1
+                    } else {
+                        test_data.answer_p2
+                    };
+                    match crate::run_part(&mut runner, &test_data.input, part, options.budget)? {
+                        answer if answer == expected => Ok(answer),
+                        answer => Err(format!("expected {} - got answer {}", expected, answer)),
+                    }
+                });
+            entries.push(Entry {
+                label: format!("Test P{} | ", part),
+                answer,
+                elapsed: now.elapsed(),
+            });
+        }
+        if status == PartStatus::ExampleOnly {
+            continue;
+        }
+
+        for profile in &options.profiles {
+            let now = Instant::now();
+            let answer = aoc::input::get_input(options.year, profile, options.day)
+                .and_then(|input| crate::run_part(&mut runner, &input, part, options.budget));
+            entries.push(Entry {
+                label: crate::part_label(part, profile),
+                answer,
+                elapsed: now.elapsed(),
+            });
+        }
+    }
+    entries
+}
+
+fn show(answer: &Result<usize, String>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e.trim_start()),
+    }
+}
+
+/// One line per entry, with the previous answer when it changed and how much
+/// faster or slower it was.
+fn render(previous: &[Entry], entries: &[Entry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        out += &format!("{}{}", entry.label, show(&entry.answer));
+
+        match previous.iter().find(|e| e.label == entry.label) {
+            Some(before) => {
+                if before.answer != entry.answer {
+                    out += &format!(" (was {})", show(&before.answer));
+                }
+                let delta = entry.elapsed.as_secs_f64() - before.elapsed.as_secs_f64();
+                out += &format!(" | {:?} ({:+.3}ms)\n", entry.elapsed, delta * 1000.0);
+            }
+            None => out += &format!(" | {:?}\n", entry.elapsed),
+        }
+    }
+    out
+}
+
+pub fn watch(options: Options) {
+    let files = watched_files(&options);
+    let mut stamps = modified(&files);
+    let mut previous = solve(&options);
+    print!("{}", render(&[], &previous));
+    eprintln!(
+        "Watching {} day {}, ctrl-c to stop",
+        options.year, options.day
+    );
+
+    loop {
+        std::thread::sleep(POLL);
+        let now = modified(&files);
+        if now == stamps {
+            continue;
+        }
+        // let the editor finish writing before reading the file
+        std::thread::sleep(POLL);
+        stamps = modified(&files);
+
+        let entries = solve(&options);
+        println!("--");
+        print!("{}", render(&previous, &entries));
+        previous = entries;
+    }
+}
+
+mod tests {
+    #[test]
+    fn render_shows_changes() {
+        use super::*;
+
+        let entry = |label: &str, answer, ms| Entry {
+            label: label.to_string(),
+            answer,
+            elapsed: Duration::from_millis(ms),
+        };
+        let before = vec![entry("Part 1  | ", Ok(5), 3), entry("Part 2  | ", Ok(7), 2)];
+        let after = vec![
+            entry("Part 1  | ", Ok(5), 2),
+            entry("Part 2  | ", Err(String::from("timed out")), 4),
+            entry("Test P1 | ", Ok(1), 1),
+        ];
+
+        assert_eq!(
+            "Part 1  | 5 | 2ms (-1.000ms)
+Part 2  | error: timed out (was 7) | 4ms (+2.000ms)
+Test P1 | 1 | 1ms
+",
+            render(&before, &after)
+        );
+    }
+}